@@ -0,0 +1,55 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+/// Errors that may be returned by the Token program
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum TokenError {
+    /// Invalid instruction
+    #[error("Invalid instruction")]
+    InvalidInstruction,
+    /// Minting would take the supply above the mint's maximum supply
+    #[error("Maximum supply exceeded")]
+    MaximumSupplyExceeded,
+    /// The maximum supply can only ever be lowered
+    #[error("Maximum supply cannot be raised")]
+    MaximumSupplyIncrease,
+    /// The source account is not allowed to send tokens of this mint
+    #[error("Sender is restricted by the compliance authority")]
+    SenderRestricted,
+    /// The destination account is not allowed to receive tokens of this mint
+    #[error("Recipient is restricted by the compliance authority")]
+    RecipientRestricted,
+    /// The transfer would exceed the account's limit for the current window
+    #[error("Transfer rate limit exceeded")]
+    RateLimitExceeded,
+    /// Tokens of a non-transferable mint cannot move between accounts
+    #[error("Transfer is disabled for this mint")]
+    NonTransferable,
+    /// An incoming transfer requires a memo immediately before it
+    #[error("No memo in previous instruction; required for recipient to receive a transfer")]
+    NoMemo,
+    /// Transfers, mints and burns are halted while the mint is paused
+    #[error("Mint is paused")]
+    MintPaused,
+    /// The maximum supply cannot be set below the current supply
+    #[error("Maximum supply cannot be lower than the current supply")]
+    MaximumSupplyBelowSupply,
+    /// Paying the claim would exceed the distribution's total reward
+    #[error("Distribution has no reward left for this claim")]
+    DistributionExhausted,
+    /// The permit is not backed by a matching Ed25519 signature of the owner
+    #[error("Permit signature is missing or does not match the transfer")]
+    InvalidPermitSignature,
+    /// Claims are no longer accepted after the distribution's deadline
+    #[error("Distribution claim period has ended")]
+    ClaimPeriodEnded,
+    /// Leftovers cannot be swept before the distribution's deadline
+    #[error("Distribution claim period has not ended")]
+    ClaimPeriodActive,
+}
+
+impl From<TokenError> for ProgramError {
+    fn from(e: TokenError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -95,6 +95,192 @@ pub enum TokenInstruction<'a> {
         // the `ui_amount` of tokens to reformat
         ui_amount: &'a str,
     },
+    InitializeInterestBearingMint {
+        // the authority allowed to update the interest rate
+        rate_authority: COption<Pubkey>,
+        // the initial interest rate in basis points
+        rate: i16,
+    },
+    UpdateRate {
+        // the new interest rate in basis points
+        rate: i16,
+    },
+    InitializeNonTransferableMint,
+    InitializePermanentDelegate {
+        // the authority that may transfer or burn from any account of the mint
+        delegate: Pubkey,
+    },
+    InitializeDefaultAccountState {
+        // the state new token accounts of the mint start in
+        state: AccountState,
+    },
+    UpdateDefaultAccountState {
+        // the new default state
+        state: AccountState,
+    },
+    EnableRequiredTransferMemos,
+    DisableRequiredTransferMemos,
+    InitializeTransferHook {
+        // the authority that can update the hook program
+        authority: COption<Pubkey>,
+        // the program invoked on every transfer
+        program_id: COption<Pubkey>,
+    },
+    UpdateTransferHook {
+        // the new hook program, `None` disables the hook
+        program_id: COption<Pubkey>,
+    },
+    EnableCpiGuard,
+    DisableCpiGuard,
+    InitializeMintCloseAuthority {
+        // the authority that can close the mint
+        close_authority: COption<Pubkey>,
+    },
+    InitializeTokenMetadata {
+        // the longer name of the token
+        name: &'a str,
+        // the shortened symbol of the token
+        symbol: &'a str,
+        // the URI pointing to richer metadata
+        uri: &'a str,
+    },
+    UpdateTokenMetadataField {
+        // the field to update
+        field: Field,
+        // the new value of the field
+        value: &'a str,
+    },
+    RemoveTokenMetadataKey {
+        // succeed even if the key is not present
+        idempotent: bool,
+        // the key to remove from the additional metadata
+        key: &'a str,
+    },
+    UpdateTokenMetadataAuthority {
+        // the new update authority
+        new_authority: COption<Pubkey>,
+    },
+    EmitTokenMetadata,
+    InitializeGroup {
+        // the authority that can add members and update the max size
+        update_authority: COption<Pubkey>,
+        // the maximum number of members
+        max_size: u64,
+    },
+    UpdateGroupMaxSize {
+        // the new maximum number of members
+        max_size: u64,
+    },
+    InitializeMember,
+    InitializePausableConfig {
+        // the authority that can pause and resume the mint
+        authority: Pubkey,
+    },
+    Pause,
+    Resume,
+    InitializeScaledUiAmount {
+        // the authority that can update the multiplier
+        authority: COption<Pubkey>,
+        // the initial UI amount multiplier
+        multiplier: f64,
+    },
+    UpdateMultiplier {
+        // the new UI amount multiplier
+        multiplier: f64,
+        // the timestamp at which the new multiplier takes effect
+        effective_timestamp: i64,
+    },
+    Reallocate {
+        // the account extensions to make room for
+        extension_types: Vec<ExtensionType>,
+    },
+    TransferBatch {
+        // the amount of tokens to transfer to each destination, in account order
+        amounts: Vec<u64>,
+        // expected number of base 10 digits to the right of the decimal place
+        decimals: u8,
+    },
+    MintToBatch {
+        // the amount of new tokens to mint to each destination, in account order
+        amounts: Vec<u64>,
+        // optional expected number of base 10 digits to the right of the decimal place
+        decimals: Option<u8>,
+    },
+    CreateVestingSchedule {
+        // the timestamp from which tokens vest linearly
+        start: i64,
+        // the timestamp before which nothing can be released
+        cliff: i64,
+        // the timestamp at which everything has vested
+        end: i64,
+        // the amount of new tokens to mint under the schedule
+        total: u64,
+    },
+    ApproveWithPolicy {
+        // the amount of tokens the delegate is allowed to transfer
+        amount: u64,
+        // the timestamp after which the delegation expires
+        expiry: i64,
+        // the largest amount the delegate may move in a single transfer
+        max_per_transfer: u64,
+    },
+    ApproveAdditional {
+        // the amount of tokens the additional delegate is allowed to transfer
+        amount: u64,
+    },
+    RevokeOne,
+    TransferWithPermit {
+        // the amount of tokens to transfer
+        amount: u64,
+        // the nonce signed in the permit, must match the source's `PermitNonce`
+        nonce: u64,
+        // the timestamp after which the permit is no longer valid
+        expiry: i64,
+    },
+    InitializeMaxSupply {
+        // the largest supply the mint may ever reach
+        max_supply: u64,
+    },
+    UpdateMaxSupply {
+        // the new, lower maximum supply
+        max_supply: u64,
+    },
+    InitializeCompliance {
+        // the authority that allows or denies accounts
+        authority: Pubkey,
+        // the treatment of accounts the authority has not decided on
+        mode: ComplianceMode,
+    },
+    SetComplianceStatus {
+        // whether the account may send tokens
+        can_send: bool,
+        // whether the account may receive tokens
+        can_receive: bool,
+    },
+    SetTransferRateLimit {
+        // the largest amount that may leave the account per window
+        limit: u64,
+        // the window length in seconds
+        window: i64,
+    },
+    InitializeRateLimitAuthority {
+        // the authority that can raise account limits
+        authority: Pubkey,
+    },
+    EnableBalanceCheckpoints,
+    GetBalanceAt {
+        // the slot to look up the balance at
+        slot: u64,
+    },
+    InitializeDistribution {
+        // the total reward deposited in the vault for holders
+        total_reward: u64,
+        // the timestamp after which claims close and leftovers can be swept
+        claim_deadline: i64,
+    },
+    Claim,
+    InitializeSupplyCheckpoint,
+    SweepDistribution,
 }
 
 impl<'a> TokenInstruction<'a> {
@@ -210,6 +396,269 @@ impl<'a> TokenInstruction<'a> {
                 let ui_amount = std::str::from_utf8(rest).map_err(|_| InvalidInstruction)?;
                 Self::UiAmountToAmount { ui_amount }
             }
+            25 => {
+                // extract the rate authority (optional 32 bytes) and the rate (2 bytes)
+                let (rate_authority, rest) = Self::unpack_pubkey_option(rest)?;
+                let (rate, _rest) = Self::unpack_rate(rest)?;
+                Self::InitializeInterestBearingMint {
+                    rate_authority,
+                    rate,
+                }
+            }
+            26 => {
+                let (rate, _rest) = Self::unpack_rate(rest)?;
+                Self::UpdateRate { rate }
+            }
+            27 => Self::InitializeNonTransferableMint,
+            28 => {
+                let (delegate, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializePermanentDelegate { delegate }
+            }
+            29 | 30 => {
+                // extract the account state (1 byte)
+                let &state = rest.first().ok_or(InvalidInstruction)?;
+                let state = AccountState::try_from_primitive(state).or(Err(InvalidInstruction))?;
+                // accounts must start usable or frozen, never uninitialized
+                if matches!(state, AccountState::Uninitialized) {
+                    return Err(InvalidInstruction.into());
+                }
+                match tag {
+                    29 => Self::InitializeDefaultAccountState { state },
+                    30 => Self::UpdateDefaultAccountState { state },
+                    _ => unreachable!(),
+                }
+            }
+            31 => Self::EnableRequiredTransferMemos,
+            32 => Self::DisableRequiredTransferMemos,
+            33 => {
+                let (authority, rest) = Self::unpack_pubkey_option(rest)?;
+                let (program_id, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::InitializeTransferHook {
+                    authority,
+                    program_id,
+                }
+            }
+            34 => {
+                let (program_id, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::UpdateTransferHook { program_id }
+            }
+            35 => Self::EnableCpiGuard,
+            36 => Self::DisableCpiGuard,
+            37 => {
+                let (close_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::InitializeMintCloseAuthority { close_authority }
+            }
+            38 => {
+                let (name, rest) = unpack_string(rest).map_err(|_| InvalidInstruction)?;
+                let (symbol, rest) = unpack_string(rest).map_err(|_| InvalidInstruction)?;
+                let (uri, _rest) = unpack_string(rest).map_err(|_| InvalidInstruction)?;
+                Self::InitializeTokenMetadata { name, symbol, uri }
+            }
+            39 => {
+                // extract the field kind (1 byte), followed by the key for `Field::Key`
+                let (&kind, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                let (field, rest) = match kind {
+                    0 => (Field::Name, rest),
+                    1 => (Field::Symbol, rest),
+                    2 => (Field::Uri, rest),
+                    3 => {
+                        let (key, rest) = unpack_string(rest).map_err(|_| InvalidInstruction)?;
+                        (Field::Key(key.to_string()), rest)
+                    }
+                    _ => return Err(InvalidInstruction.into()),
+                };
+                let (value, _rest) = unpack_string(rest).map_err(|_| InvalidInstruction)?;
+                Self::UpdateTokenMetadataField { field, value }
+            }
+            40 => {
+                let (idempotent, rest) = match rest.split_first() {
+                    Some((&0, rest)) => (false, rest),
+                    Some((&1, rest)) => (true, rest),
+                    _ => return Err(InvalidInstruction.into()),
+                };
+                let (key, _rest) = unpack_string(rest).map_err(|_| InvalidInstruction)?;
+                Self::RemoveTokenMetadataKey { idempotent, key }
+            }
+            41 => {
+                let (new_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::UpdateTokenMetadataAuthority { new_authority }
+            }
+            42 => Self::EmitTokenMetadata,
+            43 => {
+                let (update_authority, rest) = Self::unpack_pubkey_option(rest)?;
+                let (max_size, _rest) = Self::unpack_amount(rest)?;
+                Self::InitializeGroup {
+                    update_authority,
+                    max_size,
+                }
+            }
+            44 => {
+                let (max_size, _rest) = Self::unpack_amount(rest)?;
+                Self::UpdateGroupMaxSize { max_size }
+            }
+            45 => Self::InitializeMember,
+            46 => {
+                let (authority, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializePausableConfig { authority }
+            }
+            47 => Self::Pause,
+            48 => Self::Resume,
+            49 => {
+                let (authority, rest) = Self::unpack_pubkey_option(rest)?;
+                // the multiplier is sent as the little-endian bits of an f64
+                let (multiplier, _rest) = Self::unpack_amount(rest)?;
+                let multiplier = f64::from_bits(multiplier);
+                if !ScaledUiAmountConfig::is_valid_multiplier(multiplier) {
+                    return Err(InvalidInstruction.into());
+                }
+                Self::InitializeScaledUiAmount {
+                    authority,
+                    multiplier,
+                }
+            }
+            50 => {
+                let (multiplier, rest) = Self::unpack_amount(rest)?;
+                let (effective_timestamp, _rest) = Self::unpack_amount(rest)?;
+                let multiplier = f64::from_bits(multiplier);
+                if !ScaledUiAmountConfig::is_valid_multiplier(multiplier) {
+                    return Err(InvalidInstruction.into());
+                }
+                Self::UpdateMultiplier {
+                    multiplier,
+                    effective_timestamp: effective_timestamp as i64,
+                }
+            }
+            51 => {
+                // extract each extension type (2 bytes) until the input is exhausted
+                if rest.len() % 2 != 0 {
+                    return Err(InvalidInstruction.into());
+                }
+                let extension_types = rest
+                    .chunks(2)
+                    .map(|chunk| ExtensionType::from(u16::from_le_bytes([chunk[0], chunk[1]])))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Self::Reallocate { extension_types }
+            }
+            52 => {
+                // extract the decimals, then each amount (8 bytes) until the input is exhausted
+                let (&decimals, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                let amounts = Self::unpack_amounts(rest)?;
+                Self::TransferBatch { amounts, decimals }
+            }
+            53 => {
+                // extract the optional decimals (1 tag byte + 1 byte), then the amounts
+                let (decimals, rest) = match rest.split_first() {
+                    Some((&0, rest)) => (None, rest),
+                    Some((&1, rest)) => {
+                        let (&decimals, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                        (Some(decimals), rest)
+                    }
+                    _ => return Err(InvalidInstruction.into()),
+                };
+                let amounts = Self::unpack_amounts(rest)?;
+                Self::MintToBatch { amounts, decimals }
+            }
+            54 => {
+                // extract start, cliff and end (8 bytes each, signed) and the total
+                let (start, rest) = Self::unpack_amount(rest)?;
+                let (cliff, rest) = Self::unpack_amount(rest)?;
+                let (end, rest) = Self::unpack_amount(rest)?;
+                let (total, _rest) = Self::unpack_amount(rest)?;
+                let (start, cliff, end) = (start as i64, cliff as i64, end as i64);
+                if !VestingSchedule::is_valid_schedule(start, cliff, end) {
+                    return Err(InvalidInstruction.into());
+                }
+                Self::CreateVestingSchedule {
+                    start,
+                    cliff,
+                    end,
+                    total,
+                }
+            }
+            55 => {
+                let (amount, rest) = Self::unpack_amount(rest)?;
+                let (expiry, rest) = Self::unpack_amount(rest)?;
+                let (max_per_transfer, _rest) = Self::unpack_amount(rest)?;
+                Self::ApproveWithPolicy {
+                    amount,
+                    expiry: expiry as i64,
+                    max_per_transfer,
+                }
+            }
+            56 => {
+                let (amount, _rest) = Self::unpack_amount(rest)?;
+                Self::ApproveAdditional { amount }
+            }
+            57 => Self::RevokeOne,
+            58 => {
+                let (amount, rest) = Self::unpack_amount(rest)?;
+                let (nonce, rest) = Self::unpack_amount(rest)?;
+                let (expiry, _rest) = Self::unpack_amount(rest)?;
+                Self::TransferWithPermit {
+                    amount,
+                    nonce,
+                    expiry: expiry as i64,
+                }
+            }
+            59 | 60 => {
+                let (max_supply, _rest) = Self::unpack_amount(rest)?;
+                match tag {
+                    59 => Self::InitializeMaxSupply { max_supply },
+                    60 => Self::UpdateMaxSupply { max_supply },
+                    _ => unreachable!(),
+                }
+            }
+            61 => {
+                let (authority, rest) = Self::unpack_pubkey(rest)?;
+                let mode = rest
+                    .first()
+                    .ok_or(InvalidInstruction)
+                    .and_then(|&mode| ComplianceMode::from(mode).map_err(|_| InvalidInstruction))?;
+                Self::InitializeCompliance { authority, mode }
+            }
+            62 => {
+                // extract the send and receive flags (1 byte each)
+                let (can_send, can_receive) = match rest.get(..2) {
+                    Some(&[can_send, can_receive]) if can_send <= 1 && can_receive <= 1 => {
+                        (can_send == 1, can_receive == 1)
+                    }
+                    _ => return Err(InvalidInstruction.into()),
+                };
+                Self::SetComplianceStatus {
+                    can_send,
+                    can_receive,
+                }
+            }
+            63 => {
+                let (limit, rest) = Self::unpack_amount(rest)?;
+                let (window, _rest) = Self::unpack_amount(rest)?;
+                let window = window as i64;
+                // a zero window would reset on every transfer
+                if window <= 0 {
+                    return Err(InvalidInstruction.into());
+                }
+                Self::SetTransferRateLimit { limit, window }
+            }
+            64 => {
+                let (authority, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializeRateLimitAuthority { authority }
+            }
+            65 => Self::EnableBalanceCheckpoints,
+            66 => {
+                let (slot, _rest) = Self::unpack_amount(rest)?;
+                Self::GetBalanceAt { slot }
+            }
+            67 => {
+                let (total_reward, rest) = Self::unpack_amount(rest)?;
+                let (claim_deadline, _rest) = Self::unpack_amount(rest)?;
+                Self::InitializeDistribution {
+                    total_reward,
+                    claim_deadline: claim_deadline as i64,
+                }
+            }
+            68 => Self::Claim,
+            69 => Self::InitializeSupplyCheckpoint,
+            70 => Self::SweepDistribution,
             _ => return Err(TokenError::InvalidInstruction.into()),
         })
     }
@@ -316,6 +765,237 @@ impl<'a> TokenInstruction<'a> {
                 buf.push(24);
                 buf.extend_from_slice(ui_amount.as_bytes());
             }
+            &Self::InitializeInterestBearingMint {
+                ref rate_authority,
+                rate,
+            } => {
+                buf.push(25);
+                Self::pack_pubkey_option(rate_authority, &mut buf);
+                buf.extend_from_slice(&rate.to_le_bytes());
+            }
+            &Self::UpdateRate { rate } => {
+                buf.push(26);
+                buf.extend_from_slice(&rate.to_le_bytes());
+            }
+            &Self::InitializeNonTransferableMint => buf.push(27),
+            &Self::InitializePermanentDelegate { ref delegate } => {
+                buf.push(28);
+                buf.extend_from_slice(delegate.as_ref());
+            }
+            &Self::InitializeDefaultAccountState { state } => {
+                buf.push(29);
+                buf.push(state as u8);
+            }
+            &Self::UpdateDefaultAccountState { state } => {
+                buf.push(30);
+                buf.push(state as u8);
+            }
+            &Self::EnableRequiredTransferMemos => buf.push(31),
+            &Self::DisableRequiredTransferMemos => buf.push(32),
+            &Self::InitializeTransferHook {
+                ref authority,
+                ref program_id,
+            } => {
+                buf.push(33);
+                Self::pack_pubkey_option(authority, &mut buf);
+                Self::pack_pubkey_option(program_id, &mut buf);
+            }
+            &Self::UpdateTransferHook { ref program_id } => {
+                buf.push(34);
+                Self::pack_pubkey_option(program_id, &mut buf);
+            }
+            &Self::EnableCpiGuard => buf.push(35),
+            &Self::DisableCpiGuard => buf.push(36),
+            &Self::InitializeMintCloseAuthority {
+                ref close_authority,
+            } => {
+                buf.push(37);
+                Self::pack_pubkey_option(close_authority, &mut buf);
+            }
+            &Self::InitializeTokenMetadata { name, symbol, uri } => {
+                buf.push(38);
+                pack_string(name, &mut buf);
+                pack_string(symbol, &mut buf);
+                pack_string(uri, &mut buf);
+            }
+            &Self::UpdateTokenMetadataField { ref field, value } => {
+                buf.push(39);
+                match field {
+                    Field::Name => buf.push(0),
+                    Field::Symbol => buf.push(1),
+                    Field::Uri => buf.push(2),
+                    Field::Key(key) => {
+                        buf.push(3);
+                        pack_string(key, &mut buf);
+                    }
+                }
+                pack_string(value, &mut buf);
+            }
+            &Self::RemoveTokenMetadataKey { idempotent, key } => {
+                buf.push(40);
+                buf.push(idempotent as u8);
+                pack_string(key, &mut buf);
+            }
+            &Self::UpdateTokenMetadataAuthority { ref new_authority } => {
+                buf.push(41);
+                Self::pack_pubkey_option(new_authority, &mut buf);
+            }
+            &Self::EmitTokenMetadata => buf.push(42),
+            &Self::InitializeGroup {
+                ref update_authority,
+                max_size,
+            } => {
+                buf.push(43);
+                Self::pack_pubkey_option(update_authority, &mut buf);
+                buf.extend_from_slice(&max_size.to_le_bytes());
+            }
+            &Self::UpdateGroupMaxSize { max_size } => {
+                buf.push(44);
+                buf.extend_from_slice(&max_size.to_le_bytes());
+            }
+            &Self::InitializeMember => buf.push(45),
+            &Self::InitializePausableConfig { ref authority } => {
+                buf.push(46);
+                buf.extend_from_slice(authority.as_ref());
+            }
+            &Self::Pause => buf.push(47),
+            &Self::Resume => buf.push(48),
+            &Self::InitializeScaledUiAmount {
+                ref authority,
+                multiplier,
+            } => {
+                buf.push(49);
+                Self::pack_pubkey_option(authority, &mut buf);
+                buf.extend_from_slice(&multiplier.to_le_bytes());
+            }
+            &Self::UpdateMultiplier {
+                multiplier,
+                effective_timestamp,
+            } => {
+                buf.push(50);
+                buf.extend_from_slice(&multiplier.to_le_bytes());
+                buf.extend_from_slice(&effective_timestamp.to_le_bytes());
+            }
+            &Self::Reallocate {
+                ref extension_types,
+            } => {
+                buf.push(51);
+                for extension_type in extension_types.iter() {
+                    buf.extend_from_slice(&extension_type.into().to_le_bytes());
+                }
+            }
+            &Self::TransferBatch {
+                ref amounts,
+                decimals,
+            } => {
+                buf.push(52);
+                buf.push(decimals);
+                for amount in amounts.iter() {
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            &Self::MintToBatch {
+                ref amounts,
+                decimals,
+            } => {
+                buf.push(53);
+                match decimals {
+                    Some(decimals) => {
+                        buf.push(1);
+                        buf.push(decimals);
+                    }
+                    None => buf.push(0),
+                }
+                for amount in amounts.iter() {
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            &Self::CreateVestingSchedule {
+                start,
+                cliff,
+                end,
+                total,
+            } => {
+                buf.push(54);
+                buf.extend_from_slice(&start.to_le_bytes());
+                buf.extend_from_slice(&cliff.to_le_bytes());
+                buf.extend_from_slice(&end.to_le_bytes());
+                buf.extend_from_slice(&total.to_le_bytes());
+            }
+            &Self::ApproveWithPolicy {
+                amount,
+                expiry,
+                max_per_transfer,
+            } => {
+                buf.push(55);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&expiry.to_le_bytes());
+                buf.extend_from_slice(&max_per_transfer.to_le_bytes());
+            }
+            &Self::ApproveAdditional { amount } => {
+                buf.push(56);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::RevokeOne => buf.push(57),
+            &Self::TransferWithPermit {
+                amount,
+                nonce,
+                expiry,
+            } => {
+                buf.push(58);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.extend_from_slice(&expiry.to_le_bytes());
+            }
+            &Self::InitializeMaxSupply { max_supply } => {
+                buf.push(59);
+                buf.extend_from_slice(&max_supply.to_le_bytes());
+            }
+            &Self::UpdateMaxSupply { max_supply } => {
+                buf.push(60);
+                buf.extend_from_slice(&max_supply.to_le_bytes());
+            }
+            &Self::InitializeCompliance {
+                ref authority,
+                mode,
+            } => {
+                buf.push(61);
+                buf.extend_from_slice(authority.as_ref());
+                buf.push(mode.into());
+            }
+            &Self::SetComplianceStatus {
+                can_send,
+                can_receive,
+            } => {
+                buf.push(62);
+                buf.push(can_send as u8);
+                buf.push(can_receive as u8);
+            }
+            &Self::SetTransferRateLimit { limit, window } => {
+                buf.push(63);
+                buf.extend_from_slice(&limit.to_le_bytes());
+                buf.extend_from_slice(&window.to_le_bytes());
+            }
+            &Self::InitializeRateLimitAuthority { ref authority } => {
+                buf.push(64);
+                buf.extend_from_slice(authority.as_ref());
+            }
+            &Self::EnableBalanceCheckpoints => buf.push(65),
+            &Self::GetBalanceAt { slot } => {
+                buf.push(66);
+                buf.extend_from_slice(&slot.to_le_bytes());
+            }
+            &Self::InitializeDistribution {
+                total_reward,
+                claim_deadline,
+            } => {
+                buf.push(67);
+                buf.extend_from_slice(&total_reward.to_le_bytes());
+                buf.extend_from_slice(&claim_deadline.to_le_bytes());
+            }
+            &Self::Claim => buf.push(68),
+            &Self::InitializeSupplyCheckpoint => buf.push(69),
+            &Self::SweepDistribution => buf.push(70),
             _ => unreachable!(),
         };
         buf
@@ -376,6 +1056,27 @@ impl<'a> TokenInstruction<'a> {
         Ok((value, &input[u64_BYTES..]))
     }
 
+    // unpacks an interest rate (i16, basis points) from a byte slice
+    fn unpack_rate(input: &[u8]) -> Result<(i16, &[u8]), ProgramError> {
+        let rate = input
+            .get(..2)
+            .and_then(|slice| slice.try_into().ok())
+            .map(i16::from_le_bytes)
+            .ok_or(TokenError::InvalidInstruction)?;
+        Ok((rate, &input[2..]))
+    }
+
+    // unpacks a non-empty list of u64 amounts filling the rest of the input
+    fn unpack_amounts(input: &[u8]) -> Result<Vec<u64>, ProgramError> {
+        if input.is_empty() || input.len() % 8 != 0 {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+        Ok(input
+            .chunks(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect())
+    }
+
     fn unpack_amount_decimals(input: &[u8]) -> Result<(u64, u8, &[u8]), ProgramError> {
         let (amount, rest) = Self::unpack_b4(input)?;
         let (&decimals, rest) = rest.split_first().ok_or(TokenError::InvalidInstruction)?;
@@ -393,6 +1094,8 @@ pub enum AuthorityType {
     AccountOwner,
     // Authority to close a token account
     CloseAccount,
+    // Authority to close a mint with zero supply
+    MintClose,
 }
 
 impl AuthorityType {
@@ -402,6 +1105,7 @@ impl AuthorityType {
             AuthorityType::FreezeAccount => 1,
             AuthorityType::AccountOwner => 2,
             AuthorityType::CloseAccount => 3,
+            AuthorityType::MintClose => 4,
         }
     }
 
@@ -411,6 +1115,7 @@ impl AuthorityType {
             1 => Ok(AuthorityType::FreezeAccount),
             2 => Ok(AuthorityType::AccountOwner),
             3 => Ok(AuthorityType::CloseAccount),
+            4 => Ok(AuthorityType::MintClose),
             _ => Err(ProgramError::InvalidArgument.into()),
         }
     }
@@ -1097,6 +1802,1463 @@ pub fn ui_amount_to_amount(
     })
 }
 
+/// Creates an `InitializeInterestBearingMint` instruction
+pub fn initialize_interest_bearing_mint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    rate_authority_pubkey: Option<&Pubkey>,
+    rate: i16,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let rate_authority = rate_authority_pubkey.cloned().into();
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeInterestBearingMint {
+            rate_authority,
+            rate,
+        }
+        .pack(),
+    })
+}
+
+/// Creates an `UpdateRate` instruction
+pub fn update_rate(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    rate_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    rate: i16,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::UpdateRate { rate }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *rate_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeNonTransferableMint` instruction
+pub fn initialize_non_transferable_mint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeNonTransferableMint.pack(),
+    })
+}
+
+/// Creates an `InitializePermanentDelegate` instruction
+pub fn initialize_permanent_delegate(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializePermanentDelegate {
+            delegate: *delegate_pubkey,
+        }
+        .pack(),
+    })
+}
+
+/// Creates an `InitializeDefaultAccountState` instruction
+pub fn initialize_default_account_state(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    state: AccountState,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeDefaultAccountState { state }.pack(),
+    })
+}
+
+/// Creates an `UpdateDefaultAccountState` instruction
+pub fn update_default_account_state(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    freeze_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    state: AccountState,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::UpdateDefaultAccountState { state }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *freeze_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `EnableRequiredTransferMemos` instruction
+pub fn enable_required_transfer_memos(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::EnableRequiredTransferMemos.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `DisableRequiredTransferMemos` instruction
+pub fn disable_required_transfer_memos(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::DisableRequiredTransferMemos.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeTransferHook` instruction
+pub fn initialize_transfer_hook(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: Option<&Pubkey>,
+    transfer_hook_program_id: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let authority = authority_pubkey.cloned().into();
+    let program_id = transfer_hook_program_id.cloned().into();
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeTransferHook {
+            authority,
+            program_id,
+        }
+        .pack(),
+    })
+}
+
+/// Creates an `UpdateTransferHook` instruction
+pub fn update_transfer_hook(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    transfer_hook_program_id: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let program_id = transfer_hook_program_id.cloned().into();
+    let data = TokenInstruction::UpdateTransferHook { program_id }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Seed of the validation account PDA holding a hook's extra account metas
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Derives the validation account holding the extra accounts required by
+/// `transfer_hook_program_id` for transfers of `mint_pubkey`
+pub fn get_extra_account_metas_address(
+    mint_pubkey: &Pubkey,
+    transfer_hook_program_id: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[EXTRA_ACCOUNT_METAS_SEED, mint_pubkey.as_ref()],
+        transfer_hook_program_id,
+    )
+    .0
+}
+
+/// Appends the accounts a transfer hook needs to a `TransferChecked`
+/// instruction: the extra accounts resolved from the validation account's
+/// data (fetched by the caller from `get_extra_account_metas_address`),
+/// followed by the hook program and the validation account itself
+pub fn add_extra_accounts_for_transfer_hook(
+    instruction: &mut Instruction,
+    mint_pubkey: &Pubkey,
+    transfer_hook_program_id: &Pubkey,
+    validation_account_data: &[u8],
+) -> Result<(), ProgramError> {
+    let validation_pubkey = get_extra_account_metas_address(mint_pubkey, transfer_hook_program_id);
+
+    for extra_account_meta in ExtraAccountMeta::unpack_list(validation_account_data)?.iter() {
+        instruction.accounts.push(AccountMeta {
+            pubkey: extra_account_meta.pubkey,
+            is_signer: extra_account_meta.is_signer,
+            is_writable: extra_account_meta.is_writable,
+        });
+    }
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*transfer_hook_program_id, false));
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(validation_pubkey, false));
+    Ok(())
+}
+
+/// Creates an `EnableCpiGuard` instruction
+pub fn enable_cpi_guard(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::EnableCpiGuard.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `DisableCpiGuard` instruction
+pub fn disable_cpi_guard(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::DisableCpiGuard.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeMintCloseAuthority` instruction
+pub fn initialize_mint_close_authority(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    close_authority_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let close_authority = close_authority_pubkey.cloned().into();
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeMintCloseAuthority { close_authority }.pack(),
+    })
+}
+
+/// Creates an `InitializeTokenMetadata` instruction
+pub fn initialize_token_metadata(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    update_authority_pubkey: &Pubkey,
+    mint_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::InitializeTokenMetadata { name, symbol, uri }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*update_authority_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *mint_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `UpdateTokenMetadataField` instruction
+pub fn update_token_metadata_field(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    update_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    field: Field,
+    value: &str,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::UpdateTokenMetadataField { field, value }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *update_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `RemoveTokenMetadataKey` instruction
+pub fn remove_token_metadata_key(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    update_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    key: &str,
+    idempotent: bool,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::RemoveTokenMetadataKey { idempotent, key }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *update_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `UpdateTokenMetadataAuthority` instruction
+pub fn update_token_metadata_authority(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    current_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    new_authority_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let new_authority = new_authority_pubkey.cloned().into();
+    let data = TokenInstruction::UpdateTokenMetadataAuthority { new_authority }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *current_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `EmitTokenMetadata` instruction
+pub fn emit_token_metadata(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
+        data: TokenInstruction::EmitTokenMetadata.pack(),
+    })
+}
+
+/// Creates an `InitializeGroup` instruction
+pub fn initialize_group(
+    token_program_id: &Pubkey,
+    group_mint_pubkey: &Pubkey,
+    mint_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    update_authority_pubkey: Option<&Pubkey>,
+    max_size: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let update_authority = update_authority_pubkey.cloned().into();
+    let data = TokenInstruction::InitializeGroup {
+        update_authority,
+        max_size,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*group_mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *mint_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `UpdateGroupMaxSize` instruction
+pub fn update_group_max_size(
+    token_program_id: &Pubkey,
+    group_mint_pubkey: &Pubkey,
+    update_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    max_size: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::UpdateGroupMaxSize { max_size }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*group_mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *update_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeMember` instruction. Both the member mint's mint
+/// authority and the group's update authority must sign; multisig signers
+/// for either are appended after the four fixed accounts and matched by key.
+pub fn initialize_member(
+    token_program_id: &Pubkey,
+    member_mint_pubkey: &Pubkey,
+    member_mint_authority_pubkey: &Pubkey,
+    member_mint_signer_pubkeys: &[&Pubkey],
+    group_mint_pubkey: &Pubkey,
+    group_update_authority_pubkey: &Pubkey,
+    group_update_signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::InitializeMember.pack();
+
+    let mut accounts = Vec::with_capacity(
+        4 + member_mint_signer_pubkeys.len() + group_update_signer_pubkeys.len(),
+    );
+    accounts.push(AccountMeta::new(*member_mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *member_mint_authority_pubkey,
+        member_mint_signer_pubkeys.is_empty(),
+    ));
+    accounts.push(AccountMeta::new(*group_mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *group_update_authority_pubkey,
+        group_update_signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in member_mint_signer_pubkeys
+        .iter()
+        .chain(group_update_signer_pubkeys.iter())
+    {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializePausableConfig` instruction
+pub fn initialize_pausable_config(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializePausableConfig {
+            authority: *authority_pubkey,
+        }
+        .pack(),
+    })
+}
+
+/// Creates a `Pause` instruction
+pub fn pause(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::Pause.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `Resume` instruction
+pub fn resume(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::Resume.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeScaledUiAmount` instruction. The mint's config is
+/// written as `ScaledUiAmountConfig::new(authority, multiplier)`.
+pub fn initialize_scaled_ui_amount(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: Option<&Pubkey>,
+    multiplier: f64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let authority = authority_pubkey.cloned().into();
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeScaledUiAmount {
+            authority,
+            multiplier,
+        }
+        .pack(),
+    })
+}
+
+/// Creates an `UpdateMultiplier` instruction
+pub fn update_multiplier(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    multiplier: f64,
+    effective_timestamp: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::UpdateMultiplier {
+        multiplier,
+        effective_timestamp,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `Reallocate` instruction. The `payer_pubkey` funds the
+/// additional rent for the resized account.
+pub fn reallocate(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    payer_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    extension_types: &[ExtensionType],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::Reallocate {
+        extension_types: extension_types.to_vec(),
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*payer_pubkey, true));
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `TransferBatch` instruction. `amounts[i]` is sent to
+/// `destination_pubkeys[i]`.
+pub fn transfer_batch(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destination_pubkeys: &[&Pubkey],
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amounts: &[u64],
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    if amounts.is_empty() || amounts.len() != destination_pubkeys.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let data = TokenInstruction::TransferBatch {
+        amounts: amounts.to_vec(),
+        decimals,
+    }
+    .pack();
+
+    let mut accounts =
+        Vec::with_capacity(3 + destination_pubkeys.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    for destination_pubkey in destination_pubkeys.iter() {
+        accounts.push(AccountMeta::new(**destination_pubkey, false));
+    }
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `MintToBatch` instruction. `amounts[i]` is minted to
+/// `destination_pubkeys[i]`.
+pub fn mint_to_batch(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destination_pubkeys: &[&Pubkey],
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amounts: &[u64],
+    decimals: Option<u8>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    if amounts.is_empty() || amounts.len() != destination_pubkeys.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let data = TokenInstruction::MintToBatch {
+        amounts: amounts.to_vec(),
+        decimals,
+    }
+    .pack();
+
+    let mut accounts =
+        Vec::with_capacity(2 + destination_pubkeys.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    for destination_pubkey in destination_pubkeys.iter() {
+        accounts.push(AccountMeta::new(**destination_pubkey, false));
+    }
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `CreateVestingSchedule` instruction, minting `total` tokens
+/// into `account_pubkey` under the given schedule
+pub fn create_vesting_schedule(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    start: i64,
+    cliff: i64,
+    end: i64,
+    total: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::CreateVestingSchedule {
+        start,
+        cliff,
+        end,
+        total,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `ApproveWithPolicy` instruction
+pub fn approve_with_policy(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+    expiry: i64,
+    max_per_transfer: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::ApproveWithPolicy {
+        amount,
+        expiry,
+        max_per_transfer,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `ApproveAdditional` instruction
+pub fn approve_additional(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::ApproveAdditional { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `RevokeOne` instruction
+pub fn revoke_one(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::RevokeOne.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Domain tag prefixed to every permit message, so a signed permit can
+/// never be mistaken for a message of another protocol
+pub const PERMIT_DOMAIN: &[u8] = b"token-program-permit-v1";
+
+/// Builds the message the owner signs off-chain to authorize a
+/// `TransferWithPermit`: the domain tag, the token program and mint the
+/// permit is bound to, then source, destination, amount, nonce and expiry.
+/// The processor rebuilds it with its own program id and the source's mint.
+pub fn permit_message(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(PERMIT_DOMAIN.len() + 32 * 4 + 8 + 8 + 8);
+    message.extend_from_slice(PERMIT_DOMAIN);
+    message.extend_from_slice(token_program_id.as_ref());
+    message.extend_from_slice(mint_pubkey.as_ref());
+    message.extend_from_slice(source_pubkey.as_ref());
+    message.extend_from_slice(destination_pubkey.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+/// Creates a `TransferWithPermit` instruction. It must be immediately
+/// preceded by an Ed25519 program instruction verifying the owner's
+/// signature over `permit_message`; the owner does not sign the transaction.
+/// The processor checks that instruction with `check_permit_signature`.
+pub fn transfer_with_permit(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let accounts = vec![
+        AccountMeta::new(*source_pubkey, false),
+        AccountMeta::new(*destination_pubkey, false),
+        AccountMeta::new_readonly(*owner_pubkey, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data: TokenInstruction::TransferWithPermit {
+            amount,
+            nonce,
+            expiry,
+        }
+        .pack(),
+    })
+}
+
+/// Creates an `InitializeMaxSupply` instruction
+pub fn initialize_max_supply(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    max_supply: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeMaxSupply { max_supply }.pack(),
+    })
+}
+
+/// Creates an `UpdateMaxSupply` instruction
+pub fn update_max_supply(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    max_supply: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::UpdateMaxSupply { max_supply }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeCompliance` instruction
+pub fn initialize_compliance(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    mode: ComplianceMode,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeCompliance {
+            authority: *authority_pubkey,
+            mode,
+        }
+        .pack(),
+    })
+}
+
+/// Creates a `SetComplianceStatus` instruction. The account's status is
+/// marked as set, so it no longer follows the mint's `ComplianceMode`.
+pub fn set_compliance_status(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    can_send: bool,
+    can_receive: bool,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::SetComplianceStatus {
+        can_send,
+        can_receive,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `SetTransferRateLimit` instruction. `authority_pubkey` is the
+/// account owner when tightening the limit, or the mint's rate limit
+/// authority when loosening it.
+pub fn set_transfer_rate_limit(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    limit: u64,
+    window: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::SetTransferRateLimit { limit, window }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeRateLimitAuthority` instruction
+pub fn initialize_rate_limit_authority(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeRateLimitAuthority {
+            authority: *authority_pubkey,
+        }
+        .pack(),
+    })
+}
+
+/// Creates an `EnableBalanceCheckpoints` instruction. It records the
+/// current balance as the first checkpoint.
+pub fn enable_balance_checkpoints(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::EnableBalanceCheckpoints.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `GetBalanceAt` instruction
+pub fn get_balance_at(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    slot: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new_readonly(*account_pubkey, false)],
+        data: TokenInstruction::GetBalanceAt { slot }.pack(),
+    })
+}
+
+/// Seed of the per-holder `DistributionClaim` PDA
+pub const DISTRIBUTION_CLAIM_SEED: &[u8] = b"distribution-claim";
+
+/// Derives the `DistributionClaim` record of `holder_pubkey` for
+/// `distribution_pubkey`. There is exactly one per pair, which is what makes
+/// a second claim fail.
+pub fn get_distribution_claim_address(
+    token_program_id: &Pubkey,
+    distribution_pubkey: &Pubkey,
+    holder_pubkey: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            DISTRIBUTION_CLAIM_SEED,
+            distribution_pubkey.as_ref(),
+            holder_pubkey.as_ref(),
+        ],
+        token_program_id,
+    )
+    .0
+}
+
+/// Seed of the PDA that owns a distribution's vault
+pub const DISTRIBUTION_VAULT_AUTHORITY_SEED: &[u8] = b"distribution-vault";
+
+/// Derives the owner of the vault of `distribution_pubkey`. The processor
+/// rejects a vault owned by anything else and signs claim payouts with this
+/// address and its bump.
+pub fn get_distribution_vault_authority_address(
+    token_program_id: &Pubkey,
+    distribution_pubkey: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DISTRIBUTION_VAULT_AUTHORITY_SEED, distribution_pubkey.as_ref()],
+        token_program_id,
+    )
+}
+
+/// Creates an `InitializeDistribution` instruction. The mint must carry
+/// `SupplyCheckpoint`, which guarantees every holder has
+/// `BalanceCheckpoints`. The snapshot is taken at the end of the previous
+/// slot, with the supply read from that extension, and `total_reward` is
+/// transferred from `source_pubkey` into the vault in the same instruction,
+/// so a distribution is never created against an unfunded vault.
+pub fn initialize_distribution(
+    token_program_id: &Pubkey,
+    distribution_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    source_pubkey: &Pubkey,
+    vault_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    total_reward: u64,
+    claim_deadline: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::InitializeDistribution {
+        total_reward,
+        claim_deadline,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(5 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*distribution_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*vault_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `Claim` instruction, paying the holder's share of a
+/// distribution from the vault into `destination_pubkey`. The claim record
+/// is created at its PDA, funded by `payer_pubkey`.
+pub fn claim(
+    token_program_id: &Pubkey,
+    distribution_pubkey: &Pubkey,
+    holder_pubkey: &Pubkey,
+    vault_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    payer_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::Claim.pack();
+    let claim_pubkey =
+        get_distribution_claim_address(token_program_id, distribution_pubkey, holder_pubkey);
+
+    let mut accounts = Vec::with_capacity(8 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*distribution_pubkey, false));
+    accounts.push(AccountMeta::new(claim_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*holder_pubkey, false));
+    accounts.push(AccountMeta::new(*vault_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new(*payer_pubkey, true));
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates a `SweepDistribution` instruction, moving what is left in the
+/// vault after the claim deadline into `destination_pubkey`
+pub fn sweep_distribution(
+    token_program_id: &Pubkey,
+    distribution_pubkey: &Pubkey,
+    vault_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let data = TokenInstruction::SweepDistribution.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*distribution_pubkey, false));
+    accounts.push(AccountMeta::new(*vault_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Creates an `InitializeSupplyCheckpoint` instruction
+pub fn initialize_supply_checkpoint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*mint_pubkey, false)],
+        data: TokenInstruction::InitializeSupplyCheckpoint.pack(),
+    })
+}
+
 /// Utility function that checks index is between `MIN_SIGNERS` and
 /// `MAX_SIGNERS`
 pub fn is_valid_signer_index(index: usize) -> bool {
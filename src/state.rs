@@ -1,10 +1,18 @@
 use solana_program:: {
+    account_info::AccountInfo,
+    clock::{Slot, UnixTimestamp},
+    ed25519_program,
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
     program_pack::{IsInitialized, Pack, Sealed },
     program_error::ProgramError,
+    pubkey,
     pubkey::Pubkey,
+    sysvar::instructions::get_instruction_relative,
 };
 
 use spl_token::state::COption;  
+use crate::error::TokenError;
+use crate::instruction::permit_message;
 use arrayref::{array_ref, array_refs, mut_array_refs, mut_array_ref, array_mut_ref};  
 
 
@@ -237,6 +245,2037 @@ pub struct Multisig {
     pub signers: [Pubkey; MAX_SIGNERS],
 }
 
+/// Number of seconds used to annualize interest rates
+const SECONDS_PER_YEAR: f64 = 60.0 * 60.0 * 24.0 * 365.24;
+/// Number of basis points in one whole unit
+const ONE_IN_BASIS_POINTS: f64 = 10_000.0;
+
+/// Mint extension that accrues continuously compounded interest on the
+/// UI amount. The raw `Account::amount` is never modified.
+pub struct InterestBearingConfig {
+    /// Authority that may update the interest rate
+    pub rate_authority: COption<Pubkey>,
+    /// Timestamp at which interest started accruing
+    pub initialization_timestamp: UnixTimestamp,
+    /// Average rate (basis points) between initialization and the last update
+    pub pre_update_average_rate: i16,
+    /// Timestamp of the last rate update
+    pub last_update_timestamp: UnixTimestamp,
+    /// Current rate in basis points
+    pub current_rate: i16,
+}
+
+impl InterestBearingConfig {
+    /// Time weighted average of the rate since initialization, used when
+    /// the rate is updated so that already accrued interest is preserved
+    fn time_weighted_average_rate(&self, unix_timestamp: UnixTimestamp) -> Option<i16> {
+        let total_timespan = unix_timestamp.checked_sub(self.initialization_timestamp)?;
+        if total_timespan == 0 {
+            return Some(self.current_rate);
+        }
+        let pre_update_timespan = self
+            .last_update_timestamp
+            .checked_sub(self.initialization_timestamp)?;
+        let post_update_timespan = unix_timestamp.checked_sub(self.last_update_timestamp)?;
+        let pre_update_weighted = (self.pre_update_average_rate as i64).checked_mul(pre_update_timespan)?;
+        let post_update_weighted = (self.current_rate as i64).checked_mul(post_update_timespan)?;
+        let average = pre_update_weighted
+            .checked_add(post_update_weighted)?
+            .checked_div(total_timespan)?;
+        i16::try_from(average).ok()
+    }
+
+    /// Sets a new rate, folding the old one into the pre-update average
+    pub fn update_rate(&mut self, new_rate: i16, unix_timestamp: UnixTimestamp) -> Result<(), ProgramError> {
+        self.pre_update_average_rate = self
+            .time_weighted_average_rate(unix_timestamp)
+            .ok_or(ProgramError::InvalidArgument)?;
+        self.last_update_timestamp = unix_timestamp;
+        self.current_rate = new_rate;
+        Ok(())
+    }
+
+    /// exp(r * t) for a rate in basis points over a timespan in seconds
+    fn exponent(rate: i16, timespan: UnixTimestamp) -> f64 {
+        let rate = rate as f64 / ONE_IN_BASIS_POINTS;
+        let years = timespan as f64 / SECONDS_PER_YEAR;
+        (rate * years).exp()
+    }
+
+    /// Combined interest and decimal scale at `unix_timestamp`
+    fn total_scale(&self, decimals: u8, unix_timestamp: UnixTimestamp) -> Option<f64> {
+        let pre_update_timespan = self
+            .last_update_timestamp
+            .checked_sub(self.initialization_timestamp)?;
+        let post_update_timespan = unix_timestamp.checked_sub(self.last_update_timestamp)?;
+        let pre_update_exp = Self::exponent(self.pre_update_average_rate, pre_update_timespan);
+        let post_update_exp = Self::exponent(self.current_rate, post_update_timespan);
+        Some(pre_update_exp * post_update_exp / 10_f64.powi(decimals as i32))
+    }
+
+    /// Converts a raw amount to a UI amount string including accrued interest
+    pub fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: UnixTimestamp,
+    ) -> Option<String> {
+        let scale = self.total_scale(decimals, unix_timestamp)?;
+        let ui_amount = amount as f64 * scale;
+        Some(trim_ui_amount_string(format!("{:.*}", decimals as usize, ui_amount)))
+    }
+
+    /// Converts a UI amount string including accrued interest back to a raw amount
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<u64, ProgramError> {
+        let scale = self
+            .total_scale(decimals, unix_timestamp)
+            .ok_or(ProgramError::InvalidArgument)?;
+        try_ui_amount_into_scaled_amount(ui_amount, scale)
+    }
+}
+
+impl Sealed for InterestBearingConfig {}
+
+impl Pack for InterestBearingConfig {
+    const LEN: usize = 56;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 56];
+        let (
+            rate_authority,
+            initialization_timestamp,
+            pre_update_average_rate,
+            last_update_timestamp,
+            current_rate,
+        ) = array_refs![src, 36, 8, 2, 8, 2];
+
+        Ok(InterestBearingConfig {
+            rate_authority: unpack_coption_key(rate_authority)?,
+            initialization_timestamp: i64::from_le_bytes(*initialization_timestamp),
+            pre_update_average_rate: i16::from_le_bytes(*pre_update_average_rate),
+            last_update_timestamp: i64::from_le_bytes(*last_update_timestamp),
+            current_rate: i16::from_le_bytes(*current_rate),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 56];
+        let (
+            rate_authority_dst,
+            initialization_timestamp_dst,
+            pre_update_average_rate_dst,
+            last_update_timestamp_dst,
+            current_rate_dst,
+        ) = mut_array_refs![dst, 36, 8, 2, 8, 2];
+
+        pack_coption_key(&self.rate_authority, rate_authority_dst);
+        *initialization_timestamp_dst = self.initialization_timestamp.to_le_bytes();
+        *pre_update_average_rate_dst = self.pre_update_average_rate.to_le_bytes();
+        *last_update_timestamp_dst = self.last_update_timestamp.to_le_bytes();
+        *current_rate_dst = self.current_rate.to_le_bytes();
+    }
+}
+
+/// Mint extension marking tokens of the mint as non-transferable. Mint, burn
+/// and close are still permitted, but transfers between accounts are not.
+pub struct NonTransferable;
+
+impl NonTransferable {
+    /// Rejects a Transfer/TransferChecked of the mint. Called whenever the
+    /// mint carries this extension; mint, burn and close skip the check.
+    pub fn check_transfer(&self) -> Result<(), ProgramError> {
+        Err(TokenError::NonTransferable.into())
+    }
+}
+
+impl Sealed for NonTransferable {}
+
+impl Pack for NonTransferable {
+    // marker extension, presence alone carries the meaning
+    const LEN: usize = 0;
+    fn unpack_from_slice(_src: &[u8]) -> Result<Self, ProgramError> {
+        Ok(NonTransferable)
+    }
+
+    fn pack_into_slice(&self, _dst: &mut [u8]) {}
+}
+
+/// Account extension recording that the owner can never be reassigned.
+/// Set by `InitializeImmutableOwner`, and forced on every account of a
+/// `NonTransferable` mint.
+pub struct ImmutableOwner;
+
+impl Sealed for ImmutableOwner {}
+
+impl Pack for ImmutableOwner {
+    const LEN: usize = 0;
+    fn unpack_from_slice(_src: &[u8]) -> Result<Self, ProgramError> {
+        Ok(ImmutableOwner)
+    }
+
+    fn pack_into_slice(&self, _dst: &mut [u8]) {}
+}
+
+/// Mint extension naming a delegate that may transfer or burn from any
+/// account of the mint, regardless of `Account::delegate`
+pub struct PermanentDelegate {
+    /// The permanent delegate, validated like an account owner/multisig
+    pub delegate: COption<Pubkey>,
+}
+
+impl Sealed for PermanentDelegate {}
+
+impl Pack for PermanentDelegate {
+    const LEN: usize = 36;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let delegate = array_ref![src, 0, 36];
+        Ok(PermanentDelegate {
+            delegate: unpack_coption_key(delegate)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let delegate_dst = array_mut_ref![dst, 0, 36];
+        pack_coption_key(&self.delegate, delegate_dst);
+    }
+}
+
+/// Mint extension holding the state new token accounts are initialized with.
+/// `Frozen` requires the freeze authority to thaw each account before use.
+pub struct DefaultAccountState {
+    /// State given to accounts by `InitializeAccount`/`2`/`3`
+    pub state: AccountState,
+}
+
+impl Sealed for DefaultAccountState {}
+
+impl Pack for DefaultAccountState {
+    const LEN: usize = 1;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let state = array_ref![src, 0, 1];
+        Ok(DefaultAccountState {
+            state: AccountState::try_from_primitive(state[0])
+                .or(Err(ProgramError::InvalidAccountData))?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let state_dst = array_mut_ref![dst, 0, 1];
+        state_dst[0] = self.state as u8;
+    }
+}
+
+/// Program id of the SPL Memo program
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+/// Program id of the legacy v1 SPL Memo program
+pub const MEMO_V1_PROGRAM_ID: Pubkey = pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+/// Account extension requiring a memo instruction immediately before any
+/// incoming transfer
+pub struct MemoTransfer {
+    /// Require incoming transfers to be preceded by a memo
+    pub require_incoming_transfer_memos: bool,
+}
+
+impl MemoTransfer {
+    /// Rejects an incoming transfer unless the instruction immediately before
+    /// it in the transaction is a memo, read from the instructions sysvar
+    pub fn check_incoming_transfer(
+        &self,
+        instructions_sysvar_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if !self.require_incoming_transfer_memos {
+            return Ok(());
+        }
+        let previous_instruction = get_instruction_relative(-1, instructions_sysvar_info)
+            .map_err(|_| TokenError::NoMemo)?;
+        if previous_instruction.program_id == MEMO_PROGRAM_ID
+            || previous_instruction.program_id == MEMO_V1_PROGRAM_ID
+        {
+            Ok(())
+        } else {
+            Err(TokenError::NoMemo.into())
+        }
+    }
+}
+
+impl Sealed for MemoTransfer {}
+
+impl Pack for MemoTransfer {
+    const LEN: usize = 1;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        Ok(MemoTransfer {
+            require_incoming_transfer_memos: unpack_bool(array_ref![src, 0, 1])?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 1];
+        dst[0] = self.require_incoming_transfer_memos as u8;
+    }
+}
+
+/// Mint extension naming a program that `TransferChecked` invokes on every
+/// transfer of the mint
+pub struct TransferHook {
+    /// Authority that can change the hook program
+    pub authority: COption<Pubkey>,
+    /// Program invoked on every transfer, `None` disables the hook
+    pub program_id: COption<Pubkey>,
+}
+
+impl Sealed for TransferHook {}
+
+impl Pack for TransferHook {
+    const LEN: usize = 72;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 72];
+        let (authority, program_id) = array_refs![src, 36, 36];
+        Ok(TransferHook {
+            authority: unpack_coption_key(authority)?,
+            program_id: unpack_coption_key(program_id)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 72];
+        let (authority_dst, program_id_dst) = mut_array_refs![dst, 36, 36];
+        pack_coption_key(&self.authority, authority_dst);
+        pack_coption_key(&self.program_id, program_id_dst);
+    }
+}
+
+/// One extra account required by a transfer hook. The hook's validation
+/// account (see `get_extra_account_metas_address`) holds a 4-byte
+/// little-endian count followed by that many packed entries.
+pub struct ExtraAccountMeta {
+    /// Address of the extra account
+    pub pubkey: Pubkey,
+    /// Account must sign the transfer
+    pub is_signer: bool,
+    /// Account is writable during the hook
+    pub is_writable: bool,
+}
+
+impl ExtraAccountMeta {
+    /// Deserializes the extra account list stored in a validation account
+    pub fn unpack_list(src: &[u8]) -> Result<Vec<Self>, ProgramError> {
+        let (count, mut rest) = unpack_u32(src)?;
+        let mut metas = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let entry = rest
+                .get(..Self::LEN)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            metas.push(Self::unpack_from_slice(entry)?);
+            rest = &rest[Self::LEN..];
+        }
+        Ok(metas)
+    }
+
+    /// Serializes an extra account list for a validation account
+    pub fn pack_list(metas: &[Self]) -> Vec<u8> {
+        let mut buf = vec![0u8; 4 + metas.len() * Self::LEN];
+        buf[..4].copy_from_slice(&(metas.len() as u32).to_le_bytes());
+        for (i, meta) in metas.iter().enumerate() {
+            let start = 4 + i * Self::LEN;
+            meta.pack_into_slice(&mut buf[start..start + Self::LEN]);
+        }
+        buf
+    }
+}
+
+impl Sealed for ExtraAccountMeta {}
+
+impl Pack for ExtraAccountMeta {
+    const LEN: usize = 34;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 34];
+        let (pubkey, is_signer, is_writable) = array_refs![src, 32, 1, 1];
+        Ok(ExtraAccountMeta {
+            pubkey: Pubkey::new_from_array(*pubkey),
+            is_signer: unpack_bool(is_signer)?,
+            is_writable: unpack_bool(is_writable)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 34];
+        let (pubkey_dst, is_signer_dst, is_writable_dst) = mut_array_refs![dst, 32, 1, 1];
+        pubkey_dst.copy_from_slice(self.pubkey.as_ref());
+        is_signer_dst[0] = self.is_signer as u8;
+        is_writable_dst[0] = self.is_writable as u8;
+    }
+}
+
+/// Account extension that, while locked, rejects owner-signed Transfer,
+/// Approve, SetAuthority and CloseAccount made through a CPI. Delegated
+/// transfers are unaffected.
+pub struct CpiGuard {
+    /// Reject privileged owner actions when invoked via CPI
+    pub lock_cpi: bool,
+}
+
+impl CpiGuard {
+    /// Checks if the guard applies to the currently executing instruction
+    pub fn blocks_current_instruction(&self) -> bool {
+        self.lock_cpi && in_cpi()
+    }
+}
+
+impl Sealed for CpiGuard {}
+
+impl Pack for CpiGuard {
+    const LEN: usize = 1;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        Ok(CpiGuard {
+            lock_cpi: unpack_bool(array_ref![src, 0, 1])?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 1];
+        dst[0] = self.lock_cpi as u8;
+    }
+}
+
+/// Checks if the current instruction was invoked by another program rather
+/// than directly by the transaction
+pub fn in_cpi() -> bool {
+    get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT
+}
+
+/// Mint extension naming the authority allowed to close the mint with
+/// `CloseAccount` once its supply is zero
+pub struct MintCloseAuthority {
+    /// Authority that can close the mint
+    pub close_authority: COption<Pubkey>,
+}
+
+impl Sealed for MintCloseAuthority {}
+
+impl Pack for MintCloseAuthority {
+    const LEN: usize = 36;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let close_authority = array_ref![src, 0, 36];
+        Ok(MintCloseAuthority {
+            close_authority: unpack_coption_key(close_authority)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let close_authority_dst = array_mut_ref![dst, 0, 36];
+        pack_coption_key(&self.close_authority, close_authority_dst);
+    }
+}
+
+/// Mint extension holding the token's metadata. Variable length, so it is
+/// (de)serialized with its own `pack`/`unpack` instead of `Pack`, and the
+/// mint is reallocated as fields grow.
+pub struct TokenMetadata {
+    /// Authority that can update the metadata
+    pub update_authority: COption<Pubkey>,
+    /// The mint this metadata belongs to
+    pub mint: Pubkey,
+    /// Longer name of the token
+    pub name: String,
+    /// Shortened symbol of the token
+    pub symbol: String,
+    /// URI pointing to richer metadata
+    pub uri: String,
+    /// Any additional key/value pairs
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+/// Metadata field that can be updated
+pub enum Field {
+    /// The name field
+    Name,
+    /// The symbol field
+    Symbol,
+    /// The URI field
+    Uri,
+    /// A key in `additional_metadata`
+    Key(String),
+}
+
+impl TokenMetadata {
+    /// Number of bytes the serialized metadata occupies
+    pub fn get_packed_len(&self) -> usize {
+        // update_authority + mint + three length-prefixed strings + pair count
+        let mut len = 36 + 32 + 4 + self.name.len() + 4 + self.symbol.len() + 4 + self.uri.len() + 4;
+        for (key, value) in self.additional_metadata.iter() {
+            len += 4 + key.len() + 4 + value.len();
+        }
+        len
+    }
+
+    /// Updates a field, inserting the key if it is not present yet
+    pub fn update(&mut self, field: Field, value: String) {
+        match field {
+            Field::Name => self.name = value,
+            Field::Symbol => self.symbol = value,
+            Field::Uri => self.uri = value,
+            Field::Key(key) => {
+                match self.additional_metadata.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, v)) => *v = value,
+                    None => self.additional_metadata.push((key, value)),
+                }
+            }
+        }
+    }
+
+    /// Removes a key from `additional_metadata`, returning whether it existed
+    pub fn remove_key(&mut self, key: &str) -> bool {
+        let len = self.additional_metadata.len();
+        self.additional_metadata.retain(|(k, _)| k != key);
+        len != self.additional_metadata.len()
+    }
+
+    /// Serializes the metadata into a new byte vector
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.get_packed_len());
+        let mut update_authority = [0u8; 36];
+        pack_coption_key(&self.update_authority, &mut update_authority);
+        buf.extend_from_slice(&update_authority);
+        buf.extend_from_slice(self.mint.as_ref());
+        pack_string(&self.name, &mut buf);
+        pack_string(&self.symbol, &mut buf);
+        pack_string(&self.uri, &mut buf);
+        buf.extend_from_slice(&(self.additional_metadata.len() as u32).to_le_bytes());
+        for (key, value) in self.additional_metadata.iter() {
+            pack_string(key, &mut buf);
+            pack_string(value, &mut buf);
+        }
+        buf
+    }
+
+    /// Deserializes metadata from a byte slice
+    pub fn unpack(src: &[u8]) -> Result<Self, ProgramError> {
+        if src.len() < 68 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (update_authority, mint) = array_refs![array_ref![src, 0, 68], 36, 32];
+        let update_authority = unpack_coption_key(update_authority)?;
+        let mint = Pubkey::new_from_array(*mint);
+
+        let (name, rest) = unpack_string(&src[68..])?;
+        let (symbol, rest) = unpack_string(rest)?;
+        let (uri, rest) = unpack_string(rest)?;
+        let (count, mut rest) = unpack_u32(rest)?;
+        let mut additional_metadata = Vec::new();
+        for _ in 0..count {
+            let (key, next) = unpack_string(rest)?;
+            let (value, next) = unpack_string(next)?;
+            additional_metadata.push((key.to_string(), value.to_string()));
+            rest = next;
+        }
+
+        Ok(TokenMetadata {
+            update_authority,
+            mint,
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: uri.to_string(),
+            additional_metadata,
+        })
+    }
+}
+
+/// Mint extension marking the mint as a group that other mints can join
+pub struct TokenGroup {
+    /// Authority that can add members and update the max size
+    pub update_authority: COption<Pubkey>,
+    /// The group mint
+    pub mint: Pubkey,
+    /// Current number of members
+    pub size: u64,
+    /// Maximum number of members
+    pub max_size: u64,
+}
+
+impl TokenGroup {
+    /// Sets a new max size, which cannot drop below the current size
+    pub fn update_max_size(&mut self, new_max_size: u64) -> Result<(), ProgramError> {
+        if new_max_size < self.size {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.max_size = new_max_size;
+        Ok(())
+    }
+
+    /// Adds a member, returning its member number
+    pub fn increment_size(&mut self) -> Result<u64, ProgramError> {
+        let new_size = self.size.checked_add(1).ok_or(ProgramError::InvalidArgument)?;
+        if new_size > self.max_size {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.size = new_size;
+        Ok(new_size)
+    }
+}
+
+impl Sealed for TokenGroup {}
+
+impl Pack for TokenGroup {
+    const LEN: usize = 84;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 84];
+        let (update_authority, mint, size, max_size) = array_refs![src, 36, 32, 8, 8];
+        Ok(TokenGroup {
+            update_authority: unpack_coption_key(update_authority)?,
+            mint: Pubkey::new_from_array(*mint),
+            size: u64::from_le_bytes(*size),
+            max_size: u64::from_le_bytes(*max_size),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 84];
+        let (update_authority_dst, mint_dst, size_dst, max_size_dst) =
+            mut_array_refs![dst, 36, 32, 8, 8];
+        pack_coption_key(&self.update_authority, update_authority_dst);
+        mint_dst.copy_from_slice(self.mint.as_ref());
+        *size_dst = self.size.to_le_bytes();
+        *max_size_dst = self.max_size.to_le_bytes();
+    }
+}
+
+/// Mint extension marking the mint as a member of a group
+pub struct TokenGroupMember {
+    /// The member mint
+    pub mint: Pubkey,
+    /// The group mint this mint belongs to
+    pub group: Pubkey,
+    /// Position of the member in the group, starting at 1
+    pub member_number: u64,
+}
+
+impl Sealed for TokenGroupMember {}
+
+impl Pack for TokenGroupMember {
+    const LEN: usize = 72;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 72];
+        let (mint, group, member_number) = array_refs![src, 32, 32, 8];
+        Ok(TokenGroupMember {
+            mint: Pubkey::new_from_array(*mint),
+            group: Pubkey::new_from_array(*group),
+            member_number: u64::from_le_bytes(*member_number),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 72];
+        let (mint_dst, group_dst, member_number_dst) = mut_array_refs![dst, 32, 32, 8];
+        mint_dst.copy_from_slice(self.mint.as_ref());
+        group_dst.copy_from_slice(self.group.as_ref());
+        *member_number_dst = self.member_number.to_le_bytes();
+    }
+}
+
+/// Mint extension allowing an authority to halt every transfer, mint and
+/// burn of the mint at once
+pub struct PausableConfig {
+    /// Authority that can pause and resume the mint
+    pub authority: COption<Pubkey>,
+    /// Whether the mint is currently paused
+    pub paused: bool,
+}
+
+impl PausableConfig {
+    /// Rejects every Transfer, MintTo and Burn variant while paused
+    pub fn check_not_paused(&self) -> Result<(), ProgramError> {
+        if self.paused {
+            return Err(TokenError::MintPaused.into());
+        }
+        Ok(())
+    }
+}
+
+impl Sealed for PausableConfig {}
+
+impl Pack for PausableConfig {
+    const LEN: usize = 37;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 37];
+        let (authority, paused) = array_refs![src, 36, 1];
+        Ok(PausableConfig {
+            authority: unpack_coption_key(authority)?,
+            paused: unpack_bool(paused)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 37];
+        let (authority_dst, paused_dst) = mut_array_refs![dst, 36, 1];
+        pack_coption_key(&self.authority, authority_dst);
+        paused_dst[0] = self.paused as u8;
+    }
+}
+
+/// Mint extension scaling the UI amount by a multiplier, with an optional
+/// scheduled change. Used for splits and rebases without touching
+/// `Account::amount`.
+pub struct ScaledUiAmountConfig {
+    /// Authority that can set the multiplier
+    pub authority: COption<Pubkey>,
+    /// Multiplier in effect before `new_multiplier_effective_timestamp`
+    pub multiplier: f64,
+    /// Timestamp at which `new_multiplier` takes over
+    pub new_multiplier_effective_timestamp: UnixTimestamp,
+    /// Multiplier in effect from `new_multiplier_effective_timestamp`
+    pub new_multiplier: f64,
+}
+
+impl ScaledUiAmountConfig {
+    /// Config written by `InitializeScaledUiAmount`. `new_multiplier` is set
+    /// to `multiplier` with an effective timestamp of 0, so the multiplier
+    /// is in effect at every timestamp until an update is scheduled.
+    pub fn new(authority: COption<Pubkey>, multiplier: f64) -> Self {
+        ScaledUiAmountConfig {
+            authority,
+            multiplier,
+            new_multiplier_effective_timestamp: 0,
+            new_multiplier: multiplier,
+        }
+    }
+
+    /// Checks that a multiplier is finite and strictly positive
+    pub fn is_valid_multiplier(multiplier: f64) -> bool {
+        multiplier.is_finite() && multiplier > 0.0
+    }
+
+    /// Multiplier in effect at `unix_timestamp`
+    pub fn effective_multiplier(&self, unix_timestamp: UnixTimestamp) -> f64 {
+        if unix_timestamp >= self.new_multiplier_effective_timestamp {
+            self.new_multiplier
+        } else {
+            self.multiplier
+        }
+    }
+
+    /// Schedules a new multiplier. A schedule already in effect is folded into
+    /// `multiplier` first so it is not lost.
+    pub fn update_multiplier(
+        &mut self,
+        new_multiplier: f64,
+        effective_timestamp: UnixTimestamp,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        if !Self::is_valid_multiplier(new_multiplier) {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.multiplier = self.effective_multiplier(unix_timestamp);
+        if effective_timestamp <= unix_timestamp {
+            self.multiplier = new_multiplier;
+        }
+        self.new_multiplier = new_multiplier;
+        self.new_multiplier_effective_timestamp = effective_timestamp;
+        Ok(())
+    }
+
+    /// Converts a raw amount to a UI amount string using the effective multiplier
+    pub fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: UnixTimestamp,
+    ) -> String {
+        let scale = self.effective_multiplier(unix_timestamp) / 10_f64.powi(decimals as i32);
+        let ui_amount = amount as f64 * scale;
+        trim_ui_amount_string(format!("{:.*}", decimals as usize, ui_amount))
+    }
+
+    /// Converts a scaled UI amount string back to a raw amount
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<u64, ProgramError> {
+        let scale = self.effective_multiplier(unix_timestamp) / 10_f64.powi(decimals as i32);
+        try_ui_amount_into_scaled_amount(ui_amount, scale)
+    }
+}
+
+impl Sealed for ScaledUiAmountConfig {}
+
+impl Pack for ScaledUiAmountConfig {
+    const LEN: usize = 60;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 60];
+        let (authority, multiplier, new_multiplier_effective_timestamp, new_multiplier) =
+            array_refs![src, 36, 8, 8, 8];
+        Ok(ScaledUiAmountConfig {
+            authority: unpack_coption_key(authority)?,
+            multiplier: f64::from_le_bytes(*multiplier),
+            new_multiplier_effective_timestamp: i64::from_le_bytes(
+                *new_multiplier_effective_timestamp,
+            ),
+            new_multiplier: f64::from_le_bytes(*new_multiplier),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 60];
+        let (
+            authority_dst,
+            multiplier_dst,
+            new_multiplier_effective_timestamp_dst,
+            new_multiplier_dst,
+        ) = mut_array_refs![dst, 36, 8, 8, 8];
+        pack_coption_key(&self.authority, authority_dst);
+        *multiplier_dst = self.multiplier.to_le_bytes();
+        *new_multiplier_effective_timestamp_dst =
+            self.new_multiplier_effective_timestamp.to_le_bytes();
+        *new_multiplier_dst = self.new_multiplier.to_le_bytes();
+    }
+}
+
+/// Extensions that can be appended after the base `Mint`/`Account` data.
+/// Extended accounts are laid out as the base account zero-padded to
+/// `Account::LEN`, one account type byte (`ACCOUNT_TYPE_MINT` or
+/// `ACCOUNT_TYPE_ACCOUNT`), then a type (2 bytes), length (2 bytes), value
+/// entry per extension. Padding mints keeps an extended mint from ever
+/// being the same size as a plain `Account`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtensionType {
+    /// Unused slot
+    Uninitialized,
+    /// Mint: `InterestBearingConfig`
+    InterestBearingConfig,
+    /// Mint: `NonTransferable`
+    NonTransferable,
+    /// Account: `ImmutableOwner`
+    ImmutableOwner,
+    /// Mint: `PermanentDelegate`
+    PermanentDelegate,
+    /// Mint: `DefaultAccountState`
+    DefaultAccountState,
+    /// Account: `MemoTransfer`
+    MemoTransfer,
+    /// Mint: `TransferHook`
+    TransferHook,
+    /// Account: `CpiGuard`
+    CpiGuard,
+    /// Mint: `MintCloseAuthority`
+    MintCloseAuthority,
+    /// Mint: `TokenMetadata`, variable length
+    TokenMetadata,
+    /// Mint: `TokenGroup`
+    TokenGroup,
+    /// Mint: `TokenGroupMember`
+    TokenGroupMember,
+    /// Mint: `PausableConfig`
+    PausableConfig,
+    /// Mint: `ScaledUiAmountConfig`
+    ScaledUiAmountConfig,
+    /// Account: `VestingSchedule`
+    VestingSchedule,
+    /// Account: `DelegationPolicy`
+    DelegationPolicy,
+    /// Account: `AdditionalDelegates`
+    AdditionalDelegates,
+    /// Account: `PermitNonce`
+    PermitNonce,
+    /// Mint: `MaxSupply`
+    MaxSupply,
+    /// Mint: `ComplianceConfig`
+    ComplianceConfig,
+    /// Account: `ComplianceStatus`
+    ComplianceStatus,
+    /// Account: `TransferRateLimit`
+    TransferRateLimit,
+    /// Mint: `RateLimitAuthority`
+    RateLimitAuthority,
+    /// Account: `BalanceCheckpoints`
+    BalanceCheckpoints,
+    /// Mint: `SupplyCheckpoint`
+    SupplyCheckpoint,
+}
+
+/// Size of the account type byte written after the padded base account
+const ACCOUNT_TYPE_LEN: usize = 1;
+/// Account type byte of an extended `Mint`
+pub const ACCOUNT_TYPE_MINT: u8 = 1;
+/// Account type byte of an extended `Account`
+pub const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+/// Size of the type and length header in front of each extension value
+const EXTENSION_HEADER_LEN: usize = 4;
+
+impl ExtensionType {
+    /// Converts the extension type to its 2-byte tag
+    pub fn into(&self) -> u16 {
+        match self {
+            ExtensionType::Uninitialized => 0,
+            ExtensionType::InterestBearingConfig => 1,
+            ExtensionType::NonTransferable => 2,
+            ExtensionType::ImmutableOwner => 3,
+            ExtensionType::PermanentDelegate => 4,
+            ExtensionType::DefaultAccountState => 5,
+            ExtensionType::MemoTransfer => 6,
+            ExtensionType::TransferHook => 7,
+            ExtensionType::CpiGuard => 8,
+            ExtensionType::MintCloseAuthority => 9,
+            ExtensionType::TokenMetadata => 10,
+            ExtensionType::TokenGroup => 11,
+            ExtensionType::TokenGroupMember => 12,
+            ExtensionType::PausableConfig => 13,
+            ExtensionType::ScaledUiAmountConfig => 14,
+            ExtensionType::VestingSchedule => 15,
+            ExtensionType::DelegationPolicy => 16,
+            ExtensionType::AdditionalDelegates => 17,
+            ExtensionType::PermitNonce => 18,
+            ExtensionType::MaxSupply => 19,
+            ExtensionType::ComplianceConfig => 20,
+            ExtensionType::ComplianceStatus => 21,
+            ExtensionType::TransferRateLimit => 22,
+            ExtensionType::RateLimitAuthority => 23,
+            ExtensionType::BalanceCheckpoints => 24,
+            ExtensionType::SupplyCheckpoint => 25,
+        }
+    }
+
+    /// Converts a 2-byte tag back to an extension type
+    pub fn from(tag: u16) -> Result<Self, ProgramError> {
+        match tag {
+            0 => Ok(ExtensionType::Uninitialized),
+            1 => Ok(ExtensionType::InterestBearingConfig),
+            2 => Ok(ExtensionType::NonTransferable),
+            3 => Ok(ExtensionType::ImmutableOwner),
+            4 => Ok(ExtensionType::PermanentDelegate),
+            5 => Ok(ExtensionType::DefaultAccountState),
+            6 => Ok(ExtensionType::MemoTransfer),
+            7 => Ok(ExtensionType::TransferHook),
+            8 => Ok(ExtensionType::CpiGuard),
+            9 => Ok(ExtensionType::MintCloseAuthority),
+            10 => Ok(ExtensionType::TokenMetadata),
+            11 => Ok(ExtensionType::TokenGroup),
+            12 => Ok(ExtensionType::TokenGroupMember),
+            13 => Ok(ExtensionType::PausableConfig),
+            14 => Ok(ExtensionType::ScaledUiAmountConfig),
+            15 => Ok(ExtensionType::VestingSchedule),
+            16 => Ok(ExtensionType::DelegationPolicy),
+            17 => Ok(ExtensionType::AdditionalDelegates),
+            18 => Ok(ExtensionType::PermitNonce),
+            19 => Ok(ExtensionType::MaxSupply),
+            20 => Ok(ExtensionType::ComplianceConfig),
+            21 => Ok(ExtensionType::ComplianceStatus),
+            22 => Ok(ExtensionType::TransferRateLimit),
+            23 => Ok(ExtensionType::RateLimitAuthority),
+            24 => Ok(ExtensionType::BalanceCheckpoints),
+            25 => Ok(ExtensionType::SupplyCheckpoint),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    /// Checks if the extension lives on token accounts rather than mints
+    pub fn is_account_extension(&self) -> bool {
+        matches!(
+            self,
+            ExtensionType::ImmutableOwner
+                | ExtensionType::MemoTransfer
+                | ExtensionType::CpiGuard
+                | ExtensionType::VestingSchedule
+                | ExtensionType::DelegationPolicy
+                | ExtensionType::AdditionalDelegates
+                | ExtensionType::PermitNonce
+                | ExtensionType::ComplianceStatus
+                | ExtensionType::TransferRateLimit
+                | ExtensionType::BalanceCheckpoints
+        )
+    }
+
+    /// Size of the extension value, `None` for variable length extensions
+    pub fn get_type_len(&self) -> Option<usize> {
+        match self {
+            ExtensionType::Uninitialized => Some(0),
+            ExtensionType::InterestBearingConfig => Some(InterestBearingConfig::LEN),
+            ExtensionType::NonTransferable => Some(NonTransferable::LEN),
+            ExtensionType::ImmutableOwner => Some(ImmutableOwner::LEN),
+            ExtensionType::PermanentDelegate => Some(PermanentDelegate::LEN),
+            ExtensionType::DefaultAccountState => Some(DefaultAccountState::LEN),
+            ExtensionType::MemoTransfer => Some(MemoTransfer::LEN),
+            ExtensionType::TransferHook => Some(TransferHook::LEN),
+            ExtensionType::CpiGuard => Some(CpiGuard::LEN),
+            ExtensionType::MintCloseAuthority => Some(MintCloseAuthority::LEN),
+            ExtensionType::TokenMetadata => None,
+            ExtensionType::TokenGroup => Some(TokenGroup::LEN),
+            ExtensionType::TokenGroupMember => Some(TokenGroupMember::LEN),
+            ExtensionType::PausableConfig => Some(PausableConfig::LEN),
+            ExtensionType::ScaledUiAmountConfig => Some(ScaledUiAmountConfig::LEN),
+            ExtensionType::VestingSchedule => Some(VestingSchedule::LEN),
+            ExtensionType::DelegationPolicy => Some(DelegationPolicy::LEN),
+            ExtensionType::AdditionalDelegates => Some(AdditionalDelegates::LEN),
+            ExtensionType::PermitNonce => Some(PermitNonce::LEN),
+            ExtensionType::MaxSupply => Some(MaxSupply::LEN),
+            ExtensionType::ComplianceConfig => Some(ComplianceConfig::LEN),
+            ExtensionType::ComplianceStatus => Some(ComplianceStatus::LEN),
+            ExtensionType::TransferRateLimit => Some(TransferRateLimit::LEN),
+            ExtensionType::RateLimitAuthority => Some(RateLimitAuthority::LEN),
+            ExtensionType::BalanceCheckpoints => Some(BalanceCheckpoints::LEN),
+            ExtensionType::SupplyCheckpoint => Some(SupplyCheckpoint::LEN),
+        }
+    }
+
+    /// Size of a token account holding the given account extensions
+    pub fn try_get_account_len(extension_types: &[ExtensionType]) -> Result<usize, ProgramError> {
+        Self::try_get_extended_len(extension_types, true)
+    }
+
+    /// Size of a mint holding the given mint extensions, used to allocate the
+    /// mint before its `Initialize*` extension instructions run. Variable
+    /// length extensions such as `TokenMetadata` are reallocated later and
+    /// cannot be sized here.
+    pub fn try_get_mint_len(extension_types: &[ExtensionType]) -> Result<usize, ProgramError> {
+        if extension_types.is_empty() {
+            return Ok(Mint::LEN);
+        }
+        Self::try_get_extended_len(extension_types, false)
+    }
+
+    /// Padded base, account type byte and one entry per extension
+    fn try_get_extended_len(
+        extension_types: &[ExtensionType],
+        is_account: bool,
+    ) -> Result<usize, ProgramError> {
+        if extension_types.is_empty() {
+            return Ok(Account::LEN);
+        }
+        let mut len = Account::LEN + ACCOUNT_TYPE_LEN;
+        for (i, extension_type) in extension_types.iter().enumerate() {
+            if *extension_type == ExtensionType::Uninitialized
+                || extension_type.is_account_extension() != is_account
+                || extension_types[..i].contains(extension_type)
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let type_len = extension_type
+                .get_type_len()
+                .ok_or(ProgramError::InvalidArgument)?;
+            len += EXTENSION_HEADER_LEN + type_len;
+        }
+        Ok(len)
+    }
+}
+
+/// Account extension locking minted tokens behind a cliff and linear
+/// release schedule. Only the still-locked part of `total` is held back;
+/// tokens the account received from elsewhere stay freely transferable.
+pub struct VestingSchedule {
+    /// Timestamp from which tokens start vesting linearly
+    pub start: UnixTimestamp,
+    /// Timestamp before which nothing vests
+    pub cliff: UnixTimestamp,
+    /// Timestamp at which the full total has vested
+    pub end: UnixTimestamp,
+    /// Total amount under the schedule
+    pub total: u64,
+    /// Amount transferred out of the account while the schedule applied
+    pub released: u64,
+}
+
+impl VestingSchedule {
+    /// Checks if no schedule has been created yet. `Reallocate` zero-fills
+    /// the extension, and that state must not lock the account.
+    pub fn is_unset(&self) -> bool {
+        self.start == 0 && self.cliff == 0 && self.end == 0 && self.total == 0
+    }
+
+    /// Checks that `start <= cliff <= end` and that the schedule has a duration
+    pub fn is_valid(&self) -> bool {
+        Self::is_valid_schedule(self.start, self.cliff, self.end)
+    }
+
+    /// Checks schedule bounds before a `VestingSchedule` is created
+    pub fn is_valid_schedule(start: UnixTimestamp, cliff: UnixTimestamp, end: UnixTimestamp) -> bool {
+        start <= cliff && cliff <= end && start < end
+    }
+
+    /// Amount vested at `unix_timestamp`, `None` if the schedule is invalid.
+    /// An unset schedule holds nothing back.
+    pub fn vested_amount(&self, unix_timestamp: UnixTimestamp) -> Option<u64> {
+        if self.is_unset() {
+            return Some(0);
+        }
+        if !self.is_valid() {
+            return None;
+        }
+        if unix_timestamp < self.cliff {
+            Some(0)
+        } else if unix_timestamp >= self.end {
+            Some(self.total)
+        } else {
+            // widen before subtracting so extreme timestamps cannot overflow
+            let elapsed = unix_timestamp as i128 - self.start as i128;
+            let duration = self.end as i128 - self.start as i128;
+            let vested = (self.total as i128).checked_mul(elapsed)? / duration;
+            u64::try_from(vested).ok()
+        }
+    }
+
+    /// Part of `total` that has not vested yet at `unix_timestamp`
+    pub fn locked_amount(&self, unix_timestamp: UnixTimestamp) -> Option<u64> {
+        self.total.checked_sub(self.vested_amount(unix_timestamp)?)
+    }
+
+    /// Checks that moving `amount` out of an account holding `balance` leaves
+    /// at least the locked amount behind, and records the transfer
+    pub fn release(
+        &mut self,
+        balance: u64,
+        amount: u64,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        if self.is_unset() {
+            return Ok(());
+        }
+        let locked = self
+            .locked_amount(unix_timestamp)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let remaining = balance
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        if remaining < locked {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        self.released = self.released.saturating_add(amount);
+        Ok(())
+    }
+}
+
+impl Sealed for VestingSchedule {}
+
+impl Pack for VestingSchedule {
+    const LEN: usize = 40;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 40];
+        let (start, cliff, end, total, released) = array_refs![src, 8, 8, 8, 8, 8];
+        Ok(VestingSchedule {
+            start: i64::from_le_bytes(*start),
+            cliff: i64::from_le_bytes(*cliff),
+            end: i64::from_le_bytes(*end),
+            total: u64::from_le_bytes(*total),
+            released: u64::from_le_bytes(*released),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 40];
+        let (start_dst, cliff_dst, end_dst, total_dst, released_dst) =
+            mut_array_refs![dst, 8, 8, 8, 8, 8];
+        *start_dst = self.start.to_le_bytes();
+        *cliff_dst = self.cliff.to_le_bytes();
+        *end_dst = self.end.to_le_bytes();
+        *total_dst = self.total.to_le_bytes();
+        *released_dst = self.released.to_le_bytes();
+    }
+}
+
+/// Account extension bounding the current delegation with an expiry and a
+/// per-transfer cap. An expired policy is treated as no delegate on every
+/// read; the stale delegate and policy are only cleared by the next
+/// `Approve`/`Revoke`, since a failing transfer cannot persist a revoke.
+pub struct DelegationPolicy {
+    /// Timestamp from which the delegate can no longer act
+    pub expiry: UnixTimestamp,
+    /// Largest amount the delegate may move in a single Transfer/Burn
+    pub max_per_transfer: u64,
+}
+
+impl DelegationPolicy {
+    /// Checks if the delegation has expired at `unix_timestamp`, i.e. the
+    /// expiry has been reached
+    pub fn is_expired(&self, unix_timestamp: UnixTimestamp) -> bool {
+        unix_timestamp >= self.expiry
+    }
+
+    /// Allowance actually available to the delegate: `delegated_amount`
+    /// while the policy is live, zero once it has expired
+    pub fn effective_delegated_amount(&self, delegated_amount: u64, unix_timestamp: UnixTimestamp) -> u64 {
+        if self.is_expired(unix_timestamp) {
+            0
+        } else {
+            delegated_amount
+        }
+    }
+
+    /// Checks if the delegate may move `amount` at `unix_timestamp`
+    pub fn permits(&self, amount: u64, unix_timestamp: UnixTimestamp) -> bool {
+        !self.is_expired(unix_timestamp) && amount <= self.max_per_transfer
+    }
+}
+
+impl Sealed for DelegationPolicy {}
+
+impl Pack for DelegationPolicy {
+    const LEN: usize = 16;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 16];
+        let (expiry, max_per_transfer) = array_refs![src, 8, 8];
+        Ok(DelegationPolicy {
+            expiry: i64::from_le_bytes(*expiry),
+            max_per_transfer: u64::from_le_bytes(*max_per_transfer),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 16];
+        let (expiry_dst, max_per_transfer_dst) = mut_array_refs![dst, 8, 8];
+        *expiry_dst = self.expiry.to_le_bytes();
+        *max_per_transfer_dst = self.max_per_transfer.to_le_bytes();
+    }
+}
+
+/// Maximum number of delegates held by `AdditionalDelegates`
+pub const MAX_ADDITIONAL_DELEGATES: usize = 4;
+
+/// Account extension holding delegates alongside `Account::delegate`, each
+/// with its own allowance
+pub struct AdditionalDelegates {
+    /// Delegate and remaining allowance per slot, `None` marks a free slot
+    pub entries: [(COption<Pubkey>, u64); MAX_ADDITIONAL_DELEGATES],
+}
+
+impl AdditionalDelegates {
+    /// Remaining allowance of `delegate`, if it holds a slot
+    pub fn allowance(&self, delegate: &Pubkey) -> Option<u64> {
+        self.entries
+            .iter()
+            .find(|(key, _)| *key == COption::Some(*delegate))
+            .map(|(_, allowance)| *allowance)
+    }
+
+    /// Sets the allowance of `delegate`, taking a free slot if it has none.
+    /// Approving zero frees the slot, like `spend` reaching zero.
+    pub fn approve(&mut self, delegate: &Pubkey, amount: u64) -> Result<(), ProgramError> {
+        if amount == 0 {
+            self.revoke(delegate);
+            return Ok(());
+        }
+        let index = self
+            .entries
+            .iter()
+            .position(|(key, _)| *key == COption::Some(*delegate))
+            .or_else(|| self.entries.iter().position(|(key, _)| key.is_none()))
+            .ok_or(ProgramError::InvalidArgument)?;
+        self.entries[index] = (COption::Some(*delegate), amount);
+        Ok(())
+    }
+
+    /// Frees the slot of `delegate`, returning whether it held one
+    pub fn revoke(&mut self, delegate: &Pubkey) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|(key, _)| *key == COption::Some(*delegate))
+        {
+            Some(entry) => {
+                *entry = (COption::None, 0);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Spends `amount` from the allowance of `delegate`, freeing the slot
+    /// once it reaches zero
+    pub fn spend(&mut self, delegate: &Pubkey, amount: u64) -> Result<(), ProgramError> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|(key, _)| *key == COption::Some(*delegate))
+            .ok_or(ProgramError::MissingRequiredSignature)?;
+        entry.1 = entry
+            .1
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        if entry.1 == 0 {
+            *entry = (COption::None, 0);
+        }
+        Ok(())
+    }
+}
+
+impl Sealed for AdditionalDelegates {}
+
+impl Pack for AdditionalDelegates {
+    // 36 bytes for the delegate and 8 bytes for the allowance per slot
+    const LEN: usize = 44 * MAX_ADDITIONAL_DELEGATES;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 44 * MAX_ADDITIONAL_DELEGATES];
+        let mut entries = [(COption::None, 0); MAX_ADDITIONAL_DELEGATES];
+        for (i, entry) in entries.iter_mut().enumerate() {
+            let (delegate, allowance) = array_refs![array_ref![src, i * 44, 44], 36, 8];
+            *entry = (unpack_coption_key(delegate)?, u64::from_le_bytes(*allowance));
+        }
+        Ok(AdditionalDelegates { entries })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 44 * MAX_ADDITIONAL_DELEGATES];
+        for (i, (delegate, allowance)) in self.entries.iter().enumerate() {
+            let (delegate_dst, allowance_dst) =
+                mut_array_refs![array_mut_ref![dst, i * 44, 44], 36, 8];
+            pack_coption_key(delegate, delegate_dst);
+            *allowance_dst = allowance.to_le_bytes();
+        }
+    }
+}
+
+/// Account extension holding the next nonce accepted by
+/// `TransferWithPermit`, so that a signed permit cannot be replayed
+pub struct PermitNonce {
+    /// Nonce the next permit must carry
+    pub nonce: u64,
+}
+
+impl PermitNonce {
+    /// Accepts a permit carrying `nonce` and advances to the next one
+    pub fn consume(&mut self, nonce: u64) -> Result<(), ProgramError> {
+        if nonce != self.nonce {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.nonce = self
+            .nonce
+            .checked_add(1)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Ok(())
+    }
+}
+
+/// Length of the Ed25519 program instruction header: signature count and padding
+const ED25519_HEADER_LEN: usize = 2;
+/// Length of one Ed25519 signature offsets entry, seven little-endian u16s
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Ed25519 offset instruction index meaning "this same instruction"
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Checks that the instruction immediately before a `TransferWithPermit`
+/// is an Ed25519 program instruction verifying exactly one signature by
+/// `source.owner` over `permit_message`, rebuilt here from the program id,
+/// the source's mint and the transfer arguments. Every offset must point
+/// into that same instruction, so the checked pubkey and message are the
+/// ones the Ed25519 program verified.
+pub fn check_permit_signature(
+    instructions_sysvar_info: &AccountInfo,
+    program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    source: &Account,
+    destination_pubkey: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: UnixTimestamp,
+) -> Result<(), ProgramError> {
+    let instruction = get_instruction_relative(-1, instructions_sysvar_info)
+        .map_err(|_| TokenError::InvalidPermitSignature)?;
+    if instruction.program_id != ed25519_program::id() {
+        return Err(TokenError::InvalidPermitSignature.into());
+    }
+    let data = &instruction.data;
+    if data.first() != Some(&1) {
+        return Err(TokenError::InvalidPermitSignature.into());
+    }
+    let offsets = data
+        .get(ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN)
+        .ok_or(TokenError::InvalidPermitSignature)?;
+    let (
+        _signature_offset,
+        signature_instruction_index,
+        public_key_offset,
+        public_key_instruction_index,
+        message_data_offset,
+        message_data_size,
+        message_instruction_index,
+    ) = array_refs![array_ref![offsets, 0, 14], 2, 2, 2, 2, 2, 2, 2];
+    if [
+        signature_instruction_index,
+        public_key_instruction_index,
+        message_instruction_index,
+    ]
+    .iter()
+    .any(|index| u16::from_le_bytes(**index) != ED25519_CURRENT_INSTRUCTION)
+    {
+        return Err(TokenError::InvalidPermitSignature.into());
+    }
+
+    let public_key_offset = u16::from_le_bytes(*public_key_offset) as usize;
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(TokenError::InvalidPermitSignature)?;
+    if public_key != source.owner.as_ref() {
+        return Err(TokenError::InvalidPermitSignature.into());
+    }
+
+    let message_data_offset = u16::from_le_bytes(*message_data_offset) as usize;
+    let message_data_size = u16::from_le_bytes(*message_data_size) as usize;
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(TokenError::InvalidPermitSignature)?;
+    let expected_message = permit_message(
+        program_id,
+        &source.mint,
+        source_pubkey,
+        destination_pubkey,
+        amount,
+        nonce,
+        expiry,
+    );
+    if message != expected_message.as_slice() {
+        return Err(TokenError::InvalidPermitSignature.into());
+    }
+    Ok(())
+}
+
+impl Sealed for PermitNonce {}
+
+impl Pack for PermitNonce {
+    const LEN: usize = 8;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let nonce = array_ref![src, 0, 8];
+        Ok(PermitNonce {
+            nonce: u64::from_le_bytes(*nonce),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let nonce_dst = array_mut_ref![dst, 0, 8];
+        *nonce_dst = self.nonce.to_le_bytes();
+    }
+}
+
+/// Mint extension capping the supply. The cap can be lowered but never
+/// raised, and not below the current supply.
+pub struct MaxSupply {
+    /// Largest supply the mint may ever reach
+    pub max_supply: u64,
+}
+
+impl MaxSupply {
+    /// Returns the supply after minting `amount`, if it stays within the cap
+    pub fn check_mint(&self, supply: u64, amount: u64) -> Result<u64, ProgramError> {
+        let new_supply = supply
+            .checked_add(amount)
+            .ok_or(TokenError::MaximumSupplyExceeded)?;
+        if new_supply > self.max_supply {
+            return Err(TokenError::MaximumSupplyExceeded.into());
+        }
+        Ok(new_supply)
+    }
+
+    /// Lowers the cap, which must not go below the current `supply`
+    pub fn lower(&mut self, new_max_supply: u64, supply: u64) -> Result<(), ProgramError> {
+        if new_max_supply > self.max_supply {
+            return Err(TokenError::MaximumSupplyIncrease.into());
+        }
+        if new_max_supply < supply {
+            return Err(TokenError::MaximumSupplyBelowSupply.into());
+        }
+        self.max_supply = new_max_supply;
+        Ok(())
+    }
+}
+
+impl Sealed for MaxSupply {}
+
+impl Pack for MaxSupply {
+    const LEN: usize = 8;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let max_supply = array_ref![src, 0, 8];
+        Ok(MaxSupply {
+            max_supply: u64::from_le_bytes(*max_supply),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let max_supply_dst = array_mut_ref![dst, 0, 8];
+        *max_supply_dst = self.max_supply.to_le_bytes();
+    }
+}
+
+/// How a `ComplianceConfig` mint treats accounts the authority has not
+/// decided on: no `ComplianceStatus` extension, or one never set
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ComplianceMode {
+    /// Undecided accounts may neither send nor receive
+    Allowlist,
+    /// Undecided accounts may send and receive
+    Denylist,
+}
+
+impl ComplianceMode {
+    /// Converts the mode to its 1-byte tag
+    pub fn into(&self) -> u8 {
+        match self {
+            ComplianceMode::Allowlist => 0,
+            ComplianceMode::Denylist => 1,
+        }
+    }
+
+    /// Converts a 1-byte tag back to a mode
+    pub fn from(tag: u8) -> Result<Self, ProgramError> {
+        match tag {
+            0 => Ok(ComplianceMode::Allowlist),
+            1 => Ok(ComplianceMode::Denylist),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Mint extension naming the authority that sets each account's
+/// `ComplianceStatus`, and the mode applied to accounts without one
+pub struct ComplianceConfig {
+    /// Authority that can allow or deny accounts
+    pub authority: COption<Pubkey>,
+    /// Treatment of accounts the authority has not decided on
+    pub mode: ComplianceMode,
+}
+
+impl ComplianceConfig {
+    /// Checks both ends of a transfer. A side without the extension, or
+    /// whose status was never set, is resolved from `mode`, so under a
+    /// denylist a fresh account is allowed and under an allowlist it is not.
+    pub fn check_transfer(
+        &self,
+        source: Option<&ComplianceStatus>,
+        destination: Option<&ComplianceStatus>,
+    ) -> Result<(), ProgramError> {
+        if !self.resolve(source).can_send {
+            return Err(TokenError::SenderRestricted.into());
+        }
+        if !self.resolve(destination).can_receive {
+            return Err(TokenError::RecipientRestricted.into());
+        }
+        Ok(())
+    }
+
+    /// Effective status of one side of a transfer
+    fn resolve(&self, status: Option<&ComplianceStatus>) -> ComplianceStatus {
+        match status {
+            Some(status) if status.is_set => ComplianceStatus {
+                is_set: true,
+                can_send: status.can_send,
+                can_receive: status.can_receive,
+            },
+            _ => {
+                let allowed = self.mode == ComplianceMode::Denylist;
+                ComplianceStatus {
+                    is_set: false,
+                    can_send: allowed,
+                    can_receive: allowed,
+                }
+            }
+        }
+    }
+}
+
+impl Sealed for ComplianceConfig {}
+
+impl Pack for ComplianceConfig {
+    const LEN: usize = 37;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 37];
+        let (authority, mode) = array_refs![src, 36, 1];
+        Ok(ComplianceConfig {
+            authority: unpack_coption_key(authority)?,
+            mode: ComplianceMode::from(mode[0])?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 37];
+        let (authority_dst, mode_dst) = mut_array_refs![dst, 36, 1];
+        pack_coption_key(&self.authority, authority_dst);
+        mode_dst[0] = self.mode.into();
+    }
+}
+
+/// Account extension recording, per direction, whether the compliance
+/// authority allows the account to move tokens of a `ComplianceConfig` mint.
+/// `SetComplianceStatus` sets `is_set`; a zero-filled extension is undecided
+/// and follows the mint's `ComplianceMode`.
+pub struct ComplianceStatus {
+    /// The authority has decided on this account
+    pub is_set: bool,
+    /// Account may be the source of a transfer
+    pub can_send: bool,
+    /// Account may be the destination of a transfer
+    pub can_receive: bool,
+}
+
+impl Sealed for ComplianceStatus {}
+
+impl Pack for ComplianceStatus {
+    const LEN: usize = 3;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 3];
+        let (is_set, can_send, can_receive) = array_refs![src, 1, 1, 1];
+        Ok(ComplianceStatus {
+            is_set: unpack_bool(is_set)?,
+            can_send: unpack_bool(can_send)?,
+            can_receive: unpack_bool(can_receive)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 3];
+        let (is_set_dst, can_send_dst, can_receive_dst) = mut_array_refs![dst, 1, 1, 1];
+        is_set_dst[0] = self.is_set as u8;
+        can_send_dst[0] = self.can_send as u8;
+        can_receive_dst[0] = self.can_receive as u8;
+    }
+}
+
+/// Account extension capping the amount moved out of the account per
+/// window of `window` seconds
+pub struct TransferRateLimit {
+    /// Largest amount that may leave the account per window
+    pub limit: u64,
+    /// Window length in seconds
+    pub window: i64,
+    /// Start of the current window
+    pub window_start: UnixTimestamp,
+    /// Amount already moved in the current window
+    pub transferred: u64,
+}
+
+impl TransferRateLimit {
+    /// Checks if no limit has been configured yet. `Reallocate` zero-fills
+    /// the extension, and that state must not lock the account.
+    pub fn is_unset(&self) -> bool {
+        self.limit == 0 && self.window == 0
+    }
+
+    /// Records an outgoing transfer, starting a new window if the current
+    /// one has elapsed
+    pub fn record(&mut self, amount: u64, unix_timestamp: UnixTimestamp) -> Result<(), ProgramError> {
+        if self.is_unset() {
+            return Ok(());
+        }
+        if unix_timestamp.saturating_sub(self.window_start) >= self.window {
+            self.window_start = unix_timestamp;
+            self.transferred = 0;
+        }
+        let transferred = self
+            .transferred
+            .checked_add(amount)
+            .ok_or(TokenError::RateLimitExceeded)?;
+        if transferred > self.limit {
+            return Err(TokenError::RateLimitExceeded.into());
+        }
+        self.transferred = transferred;
+        Ok(())
+    }
+
+    /// Checks if the new settings are at least as strict as the current ones.
+    /// The owner may only tighten; loosening needs the mint's
+    /// `RateLimitAuthority`.
+    pub fn is_tightening(&self, new_limit: u64, new_window: i64) -> bool {
+        self.is_unset() || (new_limit <= self.limit && new_window >= self.window)
+    }
+}
+
+impl Sealed for TransferRateLimit {}
+
+impl Pack for TransferRateLimit {
+    const LEN: usize = 32;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 32];
+        let (limit, window, window_start, transferred) = array_refs![src, 8, 8, 8, 8];
+        Ok(TransferRateLimit {
+            limit: u64::from_le_bytes(*limit),
+            window: i64::from_le_bytes(*window),
+            window_start: i64::from_le_bytes(*window_start),
+            transferred: u64::from_le_bytes(*transferred),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 32];
+        let (limit_dst, window_dst, window_start_dst, transferred_dst) =
+            mut_array_refs![dst, 8, 8, 8, 8];
+        *limit_dst = self.limit.to_le_bytes();
+        *window_dst = self.window.to_le_bytes();
+        *window_start_dst = self.window_start.to_le_bytes();
+        *transferred_dst = self.transferred.to_le_bytes();
+    }
+}
+
+/// Mint extension naming the authority allowed to loosen any account's
+/// `TransferRateLimit`
+pub struct RateLimitAuthority {
+    /// Authority that can raise limits
+    pub authority: COption<Pubkey>,
+}
+
+impl Sealed for RateLimitAuthority {}
+
+impl Pack for RateLimitAuthority {
+    const LEN: usize = 36;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let authority = array_ref![src, 0, 36];
+        Ok(RateLimitAuthority {
+            authority: unpack_coption_key(authority)?,
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let authority_dst = array_mut_ref![dst, 0, 36];
+        pack_coption_key(&self.authority, authority_dst);
+    }
+}
+
+/// Maximum number of checkpoints retained by `BalanceCheckpoints`
+pub const MAX_BALANCE_CHECKPOINTS: usize = 16;
+
+/// Account extension recording `(slot, amount)` each time
+/// `Account::amount` changes via Transfer, MintTo or Burn, oldest first.
+/// Once full, a debit drops the oldest checkpoint, but a credit that follows
+/// another credit is merged into it instead. Anyone can send an account
+/// tokens, so without merging a run of dust transfers could evict the
+/// history a holder needs; with it, outsiders can drop at most one
+/// checkpoint per debit the account's own authorities make. Forced on every
+/// account of a `SupplyCheckpoint` mint.
+pub struct BalanceCheckpoints {
+    /// Number of checkpoints in use
+    pub len: u8,
+    /// Checkpoints ordered by slot, only the first `len` are valid
+    pub checkpoints: [(Slot, u64); MAX_BALANCE_CHECKPOINTS],
+}
+
+impl BalanceCheckpoints {
+    /// Creates the extension seeded with the balance at `slot`, so lookups
+    /// from the moment checkpoints are enabled succeed
+    pub fn new(slot: Slot, amount: u64) -> Self {
+        let mut checkpoints = [(0, 0); MAX_BALANCE_CHECKPOINTS];
+        checkpoints[0] = (slot, amount);
+        BalanceCheckpoints {
+            len: 1,
+            checkpoints,
+        }
+    }
+
+    /// Records the balance after a change in `slot`. Several changes in the
+    /// same slot collapse into one checkpoint. When full, a credit following
+    /// a credit replaces it, so balances between the two read as the lower
+    /// amount before the first credit rather than losing older history.
+    pub fn record(&mut self, slot: Slot, amount: u64) {
+        let len = self.len as usize;
+        if len > 0 && self.checkpoints[len - 1].0 == slot {
+            self.checkpoints[len - 1].1 = amount;
+        } else if len == MAX_BALANCE_CHECKPOINTS
+            && amount > self.checkpoints[len - 1].1
+            && self.is_credit(len - 1)
+        {
+            self.checkpoints[len - 1] = (slot, amount);
+        } else if len < MAX_BALANCE_CHECKPOINTS {
+            self.checkpoints[len] = (slot, amount);
+            self.len += 1;
+        } else {
+            self.checkpoints.rotate_left(1);
+            self.checkpoints[MAX_BALANCE_CHECKPOINTS - 1] = (slot, amount);
+        }
+    }
+
+    /// Checks if checkpoint `index` raised the balance over the one before it
+    fn is_credit(&self, index: usize) -> bool {
+        index > 0 && self.checkpoints[index].1 > self.checkpoints[index - 1].1
+    }
+
+    /// Balance at the end of `slot`, or `None` if `slot` is older than every
+    /// retained checkpoint
+    pub fn balance_at(&self, slot: Slot) -> Option<u64> {
+        self.checkpoints[..self.len as usize]
+            .iter()
+            .rev()
+            .find(|(checkpoint_slot, _)| *checkpoint_slot <= slot)
+            .map(|(_, amount)| *amount)
+    }
+}
+
+impl Sealed for BalanceCheckpoints {}
+
+impl Pack for BalanceCheckpoints {
+    // 1 byte for the length and 16 bytes (slot, amount) per checkpoint
+    const LEN: usize = 1 + 16 * MAX_BALANCE_CHECKPOINTS;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 1 + 16 * MAX_BALANCE_CHECKPOINTS];
+        let (len, data) = array_refs![src, 1, 16 * MAX_BALANCE_CHECKPOINTS];
+        let len = len[0];
+        if len as usize > MAX_BALANCE_CHECKPOINTS {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let mut checkpoints = [(0, 0); MAX_BALANCE_CHECKPOINTS];
+        for (i, checkpoint) in checkpoints.iter_mut().enumerate() {
+            let (slot, amount) = array_refs![array_ref![data, i * 16, 16], 8, 8];
+            *checkpoint = (u64::from_le_bytes(*slot), u64::from_le_bytes(*amount));
+        }
+        Ok(BalanceCheckpoints { len, checkpoints })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 1 + 16 * MAX_BALANCE_CHECKPOINTS];
+        let (len_dst, data_dst) = mut_array_refs![dst, 1, 16 * MAX_BALANCE_CHECKPOINTS];
+        len_dst[0] = self.len;
+        for (i, (slot, amount)) in self.checkpoints.iter().enumerate() {
+            let (slot_dst, amount_dst) =
+                mut_array_refs![array_mut_ref![data_dst, i * 16, 16], 8, 8];
+            *slot_dst = slot.to_le_bytes();
+            *amount_dst = amount.to_le_bytes();
+        }
+    }
+}
+
+/// Mint extension recording the supply at the start of the slot of the last
+/// MintTo or Burn, so the supply at the end of the previous slot can be read
+/// while that slot's own changes are still in flight. Every account of the
+/// mint is given `BalanceCheckpoints` at `InitializeAccount`, so every holder
+/// counted in the supply can claim from a `Distribution`.
+pub struct SupplyCheckpoint {
+    /// Slot of the last supply change
+    pub slot: Slot,
+    /// Supply before the first change in `slot`
+    pub previous_supply: u64,
+}
+
+impl SupplyCheckpoint {
+    /// Records a MintTo or Burn in `slot`, given the supply before it. Only
+    /// the first change of a slot is kept.
+    pub fn record(&mut self, slot: Slot, supply_before_change: u64) {
+        if self.slot != slot {
+            self.slot = slot;
+            self.previous_supply = supply_before_change;
+        }
+    }
+
+    /// Supply at the end of `current_slot - 1`, given the current supply
+    pub fn supply_before(&self, current_slot: Slot, supply: u64) -> u64 {
+        if self.slot == current_slot {
+            self.previous_supply
+        } else {
+            supply
+        }
+    }
+}
+
+impl Sealed for SupplyCheckpoint {}
+
+impl Pack for SupplyCheckpoint {
+    const LEN: usize = 16;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 16];
+        let (slot, previous_supply) = array_refs![src, 8, 8];
+        Ok(SupplyCheckpoint {
+            slot: u64::from_le_bytes(*slot),
+            previous_supply: u64::from_le_bytes(*previous_supply),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 16];
+        let (slot_dst, previous_supply_dst) = mut_array_refs![dst, 8, 8];
+        *slot_dst = self.slot.to_le_bytes();
+        *previous_supply_dst = self.previous_supply.to_le_bytes();
+    }
+}
+
+/// Pro-rata reward distribution against a snapshot of a mint's supply.
+/// Holders claim `amount * total_reward / snapshot_supply`, where `amount`
+/// is their balance at `snapshot_slot` taken from `BalanceCheckpoints`.
+///
+/// `InitializeDistribution` snapshots the last completed slot, the one
+/// before the current slot, and reads its supply from the mint's
+/// `SupplyCheckpoint`. Balances and supply are then both taken at the end of
+/// the same slot, so the shares never add up to more than `total_reward`.
+///
+/// Claims close at `claim_deadline`. After it the authority may sweep what
+/// is left in the vault: rounding remainders and shares of holders whose
+/// checkpoint history no longer reaches back to `snapshot_slot`.
+pub struct Distribution {
+    /// Is initialized
+    pub is_initialized: bool,
+    /// Mint whose holders are paid
+    pub mint: Pubkey,
+    /// Token account holding the deposited rewards, owned by
+    /// `get_distribution_vault_authority_address(distribution)`
+    pub vault: Pubkey,
+    /// Issuer that created the distribution
+    pub authority: Pubkey,
+    /// Last completed slot when the distribution was created; balances and
+    /// supply are taken at its end
+    pub snapshot_slot: Slot,
+    /// Supply of `mint` at `snapshot_slot`
+    pub snapshot_supply: u64,
+    /// Total reward moved into `vault` by `InitializeDistribution`
+    pub total_reward: u64,
+    /// Total reward claimed so far
+    pub claimed: u64,
+    /// Timestamp from which claims are rejected and leftovers can be swept
+    pub claim_deadline: UnixTimestamp,
+}
+
+impl Distribution {
+    /// Reward owed to a holder with `amount` tokens at the snapshot
+    pub fn claimable(&self, amount: u64) -> Option<u64> {
+        if self.snapshot_supply == 0 {
+            return None;
+        }
+        let reward = (amount as u128)
+            .checked_mul(self.total_reward as u128)?
+            .checked_div(self.snapshot_supply as u128)?;
+        u64::try_from(reward).ok()
+    }
+
+    /// Records a claim for a holder with `amount` tokens at the snapshot and
+    /// returns the reward to pay. With a consistent snapshot the shares sum
+    /// to at most `total_reward`; `DistributionExhausted` is only a safety
+    /// check and should never trigger.
+    pub fn claim(
+        &mut self,
+        amount: u64,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<u64, ProgramError> {
+        if unix_timestamp >= self.claim_deadline {
+            return Err(TokenError::ClaimPeriodEnded.into());
+        }
+        let reward = self
+            .claimable(amount)
+            .ok_or(ProgramError::InvalidArgument)?;
+        let claimed = self
+            .claimed
+            .checked_add(reward)
+            .filter(|claimed| *claimed <= self.total_reward)
+            .ok_or(TokenError::DistributionExhausted)?;
+        self.claimed = claimed;
+        Ok(reward)
+    }
+
+    /// Marks the unclaimed remainder as paid out and returns it, once the
+    /// claim period has ended
+    pub fn sweep(&mut self, unix_timestamp: UnixTimestamp) -> Result<u64, ProgramError> {
+        if unix_timestamp < self.claim_deadline {
+            return Err(TokenError::ClaimPeriodActive.into());
+        }
+        let remaining = self
+            .total_reward
+            .checked_sub(self.claimed)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        self.claimed = self.total_reward;
+        Ok(remaining)
+    }
+}
+
+impl Sealed for Distribution {}
+impl IsInitialized for Distribution {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl Pack for Distribution {
+    const LEN: usize = 137;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 137];
+        let (
+            is_initialized,
+            mint,
+            vault,
+            authority,
+            snapshot_slot,
+            snapshot_supply,
+            total_reward,
+            claimed,
+            claim_deadline,
+        ) = array_refs![src, 1, 32, 32, 32, 8, 8, 8, 8, 8];
+        Ok(Distribution {
+            is_initialized: unpack_bool(is_initialized)?,
+            mint: Pubkey::new_from_array(*mint),
+            vault: Pubkey::new_from_array(*vault),
+            authority: Pubkey::new_from_array(*authority),
+            snapshot_slot: u64::from_le_bytes(*snapshot_slot),
+            snapshot_supply: u64::from_le_bytes(*snapshot_supply),
+            total_reward: u64::from_le_bytes(*total_reward),
+            claimed: u64::from_le_bytes(*claimed),
+            claim_deadline: i64::from_le_bytes(*claim_deadline),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 137];
+        let (
+            is_initialized_dst,
+            mint_dst,
+            vault_dst,
+            authority_dst,
+            snapshot_slot_dst,
+            snapshot_supply_dst,
+            total_reward_dst,
+            claimed_dst,
+            claim_deadline_dst,
+        ) = mut_array_refs![dst, 1, 32, 32, 32, 8, 8, 8, 8, 8];
+        is_initialized_dst[0] = self.is_initialized as u8;
+        mint_dst.copy_from_slice(self.mint.as_ref());
+        vault_dst.copy_from_slice(self.vault.as_ref());
+        authority_dst.copy_from_slice(self.authority.as_ref());
+        *snapshot_slot_dst = self.snapshot_slot.to_le_bytes();
+        *snapshot_supply_dst = self.snapshot_supply.to_le_bytes();
+        *total_reward_dst = self.total_reward.to_le_bytes();
+        *claimed_dst = self.claimed.to_le_bytes();
+        *claim_deadline_dst = self.claim_deadline.to_le_bytes();
+    }
+}
+
+/// Record that a holder's token account has claimed from a `Distribution`.
+/// An initialized record means the claim was already paid. The record lives
+/// at `get_distribution_claim_address(distribution, holder)`; the processor
+/// must re-derive that address and reject any other account, otherwise a
+/// holder could claim again with a fresh record.
+pub struct DistributionClaim {
+    /// Is initialized
+    pub is_initialized: bool,
+    /// The distribution claimed from
+    pub distribution: Pubkey,
+    /// The holder's token account
+    pub holder: Pubkey,
+    /// Amount paid out
+    pub amount: u64,
+}
+
+impl Sealed for DistributionClaim {}
+impl IsInitialized for DistributionClaim {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl Pack for DistributionClaim {
+    const LEN: usize = 73;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 73];
+        let (is_initialized, distribution, holder, amount) = array_refs![src, 1, 32, 32, 8];
+        Ok(DistributionClaim {
+            is_initialized: unpack_bool(is_initialized)?,
+            distribution: Pubkey::new_from_array(*distribution),
+            holder: Pubkey::new_from_array(*holder),
+            amount: u64::from_le_bytes(*amount),
+        })
+    }
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 73];
+        let (is_initialized_dst, distribution_dst, holder_dst, amount_dst) =
+            mut_array_refs![dst, 1, 32, 32, 8];
+        is_initialized_dst[0] = self.is_initialized as u8;
+        distribution_dst.copy_from_slice(self.distribution.as_ref());
+        holder_dst.copy_from_slice(self.holder.as_ref());
+        *amount_dst = self.amount.to_le_bytes();
+    }
+}
+
 
 /// Helpers
 
@@ -273,6 +2312,15 @@ fn unpack_coption_key(src: &[u8; 36]) ->Result<COption<Pubkey>, ProgramError> {
     }
 }
 
+/// deserialize a 1-byte slice into a bool, rejecting anything but 0 or 1
+fn unpack_bool(src: &[u8; 1]) -> Result<bool, ProgramError> {
+    match src {
+        [0] => Ok(false),
+        [1] => Ok(true),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
 /// serialize a COption<u64> into a 12 byte slice
 fn pack_coption_u64(src: &COption<u64>, dst: &mut [u8; 12]) {
     let (tag, body) = mut_array_refs![dst, 4, 8];
@@ -296,3 +2344,52 @@ fn unpack_coption_u64(src: &[u8; 12]) -> Result<COption<u64>, ProgramError> {
         _ => Err(ProgramError::InvalidAccountData),
     }
 }
+
+/// removes trailing zeros and a dangling decimal point from a formatted UI amount
+fn trim_ui_amount_string(mut ui_amount: String) -> String {
+    if ui_amount.contains('.') {
+        let trimmed_len = ui_amount.trim_end_matches('0').trim_end_matches('.').len();
+        ui_amount.truncate(trimmed_len);
+    }
+    ui_amount
+}
+
+/// parses a UI amount string and converts it to a raw amount using `scale`,
+/// the factor applied to raw amounts to produce UI amounts
+fn try_ui_amount_into_scaled_amount(ui_amount: &str, scale: f64) -> Result<u64, ProgramError> {
+    let ui_amount = ui_amount
+        .parse::<f64>()
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    let amount = (ui_amount / scale).round();
+    // the `as` cast saturates, so reject anything outside of the u64 range
+    if !amount.is_finite() || amount < 0.0 || amount > u64::MAX as f64 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(amount as u64)
+}
+/// serialize a string as a 4-byte little-endian length followed by its bytes,
+/// shared by account state and instruction data
+pub(crate) fn pack_string(src: &str, dst: &mut Vec<u8>) {
+    dst.extend_from_slice(&(src.len() as u32).to_le_bytes());
+    dst.extend_from_slice(src.as_bytes());
+}
+
+/// deserialize a 4-byte little-endian u32 from the front of a slice
+fn unpack_u32(src: &[u8]) -> Result<(u32, &[u8]), ProgramError> {
+    let value = src
+        .get(..4)
+        .and_then(|slice| slice.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok((value, &src[4..]))
+}
+
+/// deserialize a length-prefixed string from the front of a slice, shared by
+/// account state and instruction data
+pub(crate) fn unpack_string(src: &[u8]) -> Result<(&str, &[u8]), ProgramError> {
+    let (len, rest) = unpack_u32(src)?;
+    let len = len as usize;
+    let bytes = rest.get(..len).ok_or(ProgramError::InvalidAccountData)?;
+    let value = std::str::from_utf8(bytes).map_err(|_| ProgramError::InvalidAccountData)?;
+    Ok((value, &rest[len..]))
+}